@@ -3,6 +3,8 @@ use leptos::{ev::SubmitEvent, prelude::*};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
+use std::time::Duration;
+
 use crate::components::{
     Button, ButtonVariant, ButtonColor, ButtonSize,
     TextField, TextFieldVariant,
@@ -12,7 +14,9 @@ use crate::components::{
     Alert, AlertSeverity,
     Tooltip, TooltipPlacement,
     // Dialog, DialogTitle, DialogContent, DialogActions,
+    ConfirmDialog,
     Typography, TypographyVariant, TypographyColor,
+    ToastProvider, ToastContainer, use_toasts,
 };
 
 #[wasm_bindgen]
@@ -36,7 +40,7 @@ pub fn App() -> impl IntoView {
     let (checkbox_checked, set_checkbox_checked) = signal(false);
     let (switch_checked, set_switch_checked) = signal(false);
     let (alert_open, set_alert_open) = signal(true);
-    // let (dialog_open, set_dialog_open) = signal(false); // Dialogコンポーネントは一時的に無効化
+    let (confirm_open, set_confirm_open) = signal(false);
 
     let update_name = move |ev| {
         let v = event_target_value(&ev);
@@ -59,6 +63,7 @@ pub fn App() -> impl IntoView {
     };
 
     view! {
+        <ToastProvider>
         <main class="min-h-screen bg-gradient-to-br from-background-default to-background-paper p-8">
             <div class="max-w-4xl mx-auto">
                 <h1 class="text-4xl font-bold text-text-primary mb-8 text-center">
@@ -188,9 +193,9 @@ pub fn App() -> impl IntoView {
                                         set_value=set_select_value
                                         label="Select Option"
                                     >
-                                        <SelectOption value="option1".to_string()>"Option 1"</SelectOption>
-                                        <SelectOption value="option2".to_string()>"Option 2"</SelectOption>
-                                        <SelectOption value="option3".to_string()>"Option 3"</SelectOption>
+                                        <SelectOption value="option1".to_string() label="Option 1">"Option 1"</SelectOption>
+                                        <SelectOption value="option2".to_string() label="Option 2">"Option 2"</SelectOption>
+                                        <SelectOption value="option3".to_string() label="Option 3">"Option 3"</SelectOption>
                                     </Select>
                                 </div>
                             </div>
@@ -252,17 +257,67 @@ pub fn App() -> impl IntoView {
                                 </div>
                             </div>
 
-                            // Dialogコンポーネントは一時的に無効化
-                            // <div>
-                            //     <Typography variant=TypographyVariant::Subtitle1 class="mb-2">
-                            //         "Dialog"
-                            //     </Typography>
-                            //     ...
-                            // </div>
+                            <div>
+                                <Typography variant=TypographyVariant::Subtitle1 class="mb-2">
+                                    "Toast"
+                                </Typography>
+                                <ToastDemo/>
+                            </div>
+
+                            <div>
+                                <Typography variant=TypographyVariant::Subtitle1 class="mb-2">
+                                    "Dialog"
+                                </Typography>
+                                <div class="space-y-2">
+                                    <Button
+                                        variant=ButtonVariant::Contained
+                                        color=ButtonColor::Error
+                                        on_click=move |_| set_confirm_open.set(true)
+                                    >
+                                        "Delete item…"
+                                    </Button>
+                                    <ConfirmDialog
+                                        open=confirm_open
+                                        title="Delete this item?"
+                                        description="This action cannot be undone."
+                                        verb="Hold to delete"
+                                        hold=true
+                                        on_confirm=Callback::new(move |_| set_confirm_open.set(false))
+                                        on_cancel=Callback::new(move |_| set_confirm_open.set(false))
+                                    />
+                                </div>
+                            </div>
                         </div>
                     </div>
                 </div>
             </div>
         </main>
+        <ToastContainer/>
+        </ToastProvider>
+    }
+}
+
+/// `ToastProvider` 配下でのみ使用可能なデモボタン。`use_toasts()` で
+/// キューへ積むだけで、表示・自動消滅は `ToastContainer` 側に任せる
+#[component]
+fn ToastDemo() -> impl IntoView {
+    let toasts = use_toasts();
+
+    view! {
+        <div class="flex flex-wrap gap-2">
+            <Button
+                color=ButtonColor::Success
+                on_click=move |_| {
+                    toasts.push_toast(
+                        AlertSeverity::Success,
+                        Some("Saved".to_string()),
+                        "Changes saved successfully.",
+                        Duration::from_secs(5),
+                    );
+                }
+            >
+                "Show toast"
+            </Button>
+        </div>
     }
 }
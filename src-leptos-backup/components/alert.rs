@@ -16,7 +16,7 @@ pub fn Alert(
     children: Children,
     #[prop(optional)] severity: Option<AlertSeverity>,
     #[prop(optional, into)] title: Option<String>,
-    // on_closeは後で実装（Callbackの問題を解決後に追加）
+    #[prop(optional, into)] on_close: Option<Callback<()>>,
     #[prop(optional, into)] class: Option<String>,
 ) -> impl IntoView {
     let severity = severity.unwrap_or(AlertSeverity::Info);
@@ -70,7 +70,16 @@ pub fn Alert(
                         {children()}
                     </div>
                 </div>
-                // 閉じるボタンは後で実装
+                {on_close.map(|cb| {
+                    view! {
+                        <button
+                            class=format!("shrink-0 {} hover:opacity-70", icon_class)
+                            on:click=move |_| cb.run(())
+                        >
+                            "×"
+                        </button>
+                    }
+                })}
             </div>
         </div>
     }
@@ -0,0 +1,301 @@
+// Dialog Component
+// MUI DialogのLeptos実装。加えて、Trezorの `confirm_action` レイアウトを
+// モデルにした `ConfirmDialog` を提供する。`hold: true` のときは確認ボタンを
+// 一定時間押し続けるまで `on_confirm` が発火しない、破壊的操作向けの確認手段になる
+
+use std::time::Duration;
+
+use leptos::ev;
+use leptos::leptos_dom::helpers::{set_interval_with_handle, IntervalHandle};
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+}
+
+#[component]
+pub fn Dialog(
+    children: Children,
+    #[prop(into)] open: Signal<bool>,
+    #[prop(optional, into)] on_close: Option<WriteSignal<bool>>,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    let children_view = children();
+    view! {
+        <Show when=move || open.get()>
+            <div
+                class="fixed inset-0 z-50 flex items-center justify-center bg-black/50 backdrop-blur-sm"
+                on:click=move |ev| {
+                    // バックドロップクリックで閉じる
+                    if ev.target() == ev.current_target() {
+                        if let Some(set_open) = on_close {
+                            set_open.set(false);
+                        }
+                    }
+                }
+            >
+                <div
+                    class=format!(
+                        "bg-background-paper rounded-lg shadow-lg max-w-lg w-full mx-4 {}",
+                        class.clone().unwrap_or_default()
+                    )
+                    on:click=|ev| {
+                        ev.stop_propagation();
+                    }
+                >
+                    {children_view}
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+#[component]
+pub fn DialogTitle(
+    children: Children,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    view! {
+        <div class=format!("px-6 py-4 border-b border-grey-600 {}", class.unwrap_or_default())>
+            <h2 class="text-xl font-semibold text-text-primary">
+                {children()}
+            </h2>
+        </div>
+    }
+}
+
+#[component]
+pub fn DialogContent(
+    children: Children,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    view! {
+        <div class=format!("px-6 py-4 {}", class.unwrap_or_default())>
+            {children()}
+        </div>
+    }
+}
+
+#[component]
+pub fn DialogActions(
+    children: Children,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    view! {
+        <div class=format!("px-6 py-4 border-t border-grey-600 flex justify-end gap-2 {}", class.unwrap_or_default())>
+            {children()}
+        </div>
+    }
+}
+
+/// `hold` 確認中にプログレスを刻む間隔
+const HOLD_TICK: Duration = Duration::from_millis(30);
+
+/// 破壊的な操作を一段守る確認ダイアログ。`hold` を立てると確認ボタンを
+/// `hold_duration` の間押し続けるまで `on_confirm` が発火せず、離すと進捗が
+/// 0へ戻る。Escapeとバックドロップクリックはどちらもキャンセル扱いになる
+#[component]
+pub fn ConfirmDialog(
+    #[prop(into)] open: Signal<bool>,
+    #[prop(into)] title: String,
+    #[prop(into)] description: String,
+    #[prop(optional, into)] verb: Option<String>,
+    #[prop(optional, into)] verb_cancel: Option<String>,
+    #[prop(optional)] hold: Option<bool>,
+    #[prop(optional)] hold_duration: Option<Duration>,
+    on_confirm: Callback<()>,
+    on_cancel: Callback<()>,
+) -> impl IntoView {
+    let verb = verb.unwrap_or_else(|| "Confirm".to_string());
+    let verb_cancel = verb_cancel.unwrap_or_else(|| "Cancel".to_string());
+    let hold = hold.unwrap_or(false);
+    let hold_duration = hold_duration.unwrap_or(Duration::from_millis(800));
+
+    let (progress, set_progress) = signal(0.0f64);
+    let interval_handle = StoredValue::new(None::<IntervalHandle>);
+    let dialog_ref = NodeRef::<leptos::html::Div>::new();
+
+    let clear_interval = move || {
+        interval_handle.update_value(|handle| {
+            if let Some(handle) = handle.take() {
+                handle.clear();
+            }
+        });
+    };
+
+    let confirm_now = move || {
+        clear_interval();
+        set_progress.set(0.0);
+        on_confirm.run(());
+    };
+
+    let cancel_now = move || {
+        clear_interval();
+        set_progress.set(0.0);
+        on_cancel.run(());
+    };
+
+    let start_hold = move || {
+        if !hold {
+            return;
+        }
+        set_progress.set(0.0);
+        let step = HOLD_TICK.as_secs_f64() / hold_duration.as_secs_f64();
+        let handle = set_interval_with_handle(
+            move || {
+                let next = (progress.get_untracked() + step).min(1.0);
+                set_progress.set(next);
+                if next >= 1.0 {
+                    confirm_now();
+                }
+            },
+            HOLD_TICK,
+        )
+        .ok();
+        interval_handle.set_value(handle);
+    };
+
+    let release_hold = move || {
+        if hold {
+            clear_interval();
+            set_progress.set(0.0);
+        }
+    };
+
+    // 開いている間、ダイアログへフォーカスし、Escapeをキャンセルとして扱い、
+    // Tabでのフォーカスをダイアログ内に閉じ込める
+    Effect::new(move |_| {
+        if !open.get() {
+            return;
+        }
+
+        if let Some(el) = dialog_ref.get() {
+            let _ = el.focus();
+        }
+
+        let keydown_handle = window_event_listener(ev::keydown, move |ev| {
+            match ev.key().as_str() {
+                "Escape" => cancel_now(),
+                "Tab" => trap_focus(&dialog_ref, ev.shift_key(), &ev),
+                _ => {}
+            }
+        });
+
+        on_cleanup(move || {
+            keydown_handle.remove();
+            clear_interval();
+        });
+    });
+
+    view! {
+        <Show when=move || open.get()>
+            <div
+                class="fixed inset-0 z-50 flex items-center justify-center bg-black/50 backdrop-blur-sm"
+                on:click=move |ev| {
+                    if ev.target() == ev.current_target() {
+                        cancel_now();
+                    }
+                }
+            >
+                <div
+                    node_ref=dialog_ref
+                    tabindex="-1"
+                    class="bg-background-paper rounded-lg shadow-lg max-w-sm w-full mx-4 outline-none"
+                    on:click=|ev| ev.stop_propagation()
+                >
+                    <div class="px-6 py-4 border-b border-grey-600">
+                        <h2 class="text-xl font-semibold text-text-primary">{title.clone()}</h2>
+                    </div>
+                    <div class="px-6 py-4 text-sm text-text-secondary">{description.clone()}</div>
+                    <div class="px-6 py-4 border-t border-grey-600 flex justify-end gap-2">
+                        <button
+                            class="px-4 py-2 rounded-md text-text-primary hover:bg-background-menu"
+                            on:click=move |_| cancel_now()
+                        >
+                            {verb_cancel.clone()}
+                        </button>
+                        <button
+                            class="relative overflow-hidden px-4 py-2 rounded-md bg-error-500 text-white hover:bg-error-600"
+                            on:pointerdown=move |_| start_hold()
+                            on:pointerup=move |_| release_hold()
+                            on:pointerleave=move |_| release_hold()
+                            on:click=move |_| {
+                                if !hold {
+                                    confirm_now();
+                                }
+                            }
+                        >
+                            {hold.then(|| view! {
+                                <span
+                                    class="absolute inset-0 bg-white/25"
+                                    style=move || format!("width: {}%", progress.get() * 100.0)
+                                ></span>
+                            })}
+                            <span class="relative">{verb.clone()}</span>
+                        </button>
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+/// ダイアログ内の先頭/末尾のフォーカス可能要素をTabで折り返させ、外へ出さない
+fn trap_focus(dialog_ref: &NodeRef<leptos::html::Div>, shift: bool, ev: &ev::KeyboardEvent) {
+    let Some(container) = dialog_ref.get() else {
+        return;
+    };
+    let Ok(list) = container.query_selector_all(
+        "button:not([disabled]), [href], input, select, textarea, [tabindex]:not([tabindex='-1'])",
+    ) else {
+        return;
+    };
+    let len = list.length();
+    if len == 0 {
+        return;
+    }
+
+    let active = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.active_element())
+        .and_then(|e| e.dyn_into::<web_sys::Node>().ok());
+    let first = list.get(0).and_then(|n| n.dyn_into::<web_sys::Node>().ok());
+    let last = list.get(len - 1).and_then(|n| n.dyn_into::<web_sys::Node>().ok());
+
+    let is_same = |a: &Option<web_sys::Node>, b: &Option<web_sys::Node>| match (a, b) {
+        (Some(a), Some(b)) => a.is_same_node(Some(b)),
+        _ => false,
+    };
+
+    if shift && is_same(&active, &first) {
+        ev.prevent_default();
+        if let Some(last) = last.and_then(|n| n.dyn_into::<web_sys::HtmlElement>().ok()) {
+            let _ = last.focus();
+        }
+    } else if !shift && is_same(&active, &last) {
+        ev.prevent_default();
+        if let Some(first) = first.and_then(|n| n.dyn_into::<web_sys::HtmlElement>().ok()) {
+            let _ = first.focus();
+        }
+    }
+}
+
+/// `on_confirm` を型付きの `invoke(cmd, args)` 呼び出しへ直結させる薄いヘルパー。
+/// 破壊的なコマンドを `ConfirmDialog` の確認の裏に隠すために使う
+pub fn invoke_on_confirm<A>(cmd: &'static str, args: A) -> Callback<()>
+where
+    A: serde::Serialize + Clone + 'static,
+{
+    Callback::new(move |_| {
+        let args = args.clone();
+        spawn_local(async move {
+            let js_args = serde_wasm_bindgen::to_value(&args).unwrap_or(JsValue::NULL);
+            invoke(cmd, js_args).await;
+        });
+    })
+}
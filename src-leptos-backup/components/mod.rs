@@ -7,8 +7,9 @@ pub mod select;
 pub mod checkbox;
 pub mod switch;
 pub mod alert;
+pub mod toast;
 pub mod tooltip;
-// pub mod dialog; // 一時的に無効化（Showコンポーネントの問題を解決後に有効化）
+pub mod dialog;
 pub mod typography;
 
 pub use button::{Button, ButtonVariant, ButtonColor, ButtonSize};
@@ -17,7 +18,8 @@ pub use select::{Select, SelectVariant, SelectOption};
 pub use checkbox::Checkbox;
 pub use switch::{Switch, SwitchColor};
 pub use alert::{Alert, AlertSeverity};
+pub use toast::{use_toasts, Toast, ToastContainer, ToastContext, ToastProvider};
 pub use tooltip::{Tooltip, TooltipPlacement};
-// pub use dialog::{Dialog, DialogTitle, DialogContent, DialogActions};
+pub use dialog::{ConfirmDialog, Dialog, DialogActions, DialogContent, DialogTitle};
 pub use typography::{Typography, TypographyVariant, TypographyColor};
 
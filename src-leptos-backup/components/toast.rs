@@ -0,0 +1,159 @@
+// Toast / Notification Stack
+// `Alert`はインラインバナー1件を描画するだけでライフサイクルを持たない。
+// `ToastProvider`が通知キューをコンテキストとして保持し、`push_toast`で追加された
+// 通知を`ToastContainer`が画面隅に積み上げて表示、タイムアウトで自動的に消す。
+// バックエンドからは `app-toast` イベントを発行するだけで同じキューに合流できる
+
+use std::time::Duration;
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use serde::Deserialize;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::prelude::*;
+
+use super::alert::{Alert, AlertSeverity};
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"])]
+    async fn listen(event: &str, handler: &js_sys::Function) -> JsValue;
+}
+
+#[derive(Clone)]
+pub struct Toast {
+    pub id: u64,
+    pub severity: AlertSeverity,
+    pub title: Option<String>,
+    pub message: String,
+}
+
+/// バックエンドが `app-toast` イベントで送ってくるペイロード
+#[derive(Deserialize)]
+struct AppToastPayload {
+    severity: String,
+    title: Option<String>,
+    message: String,
+}
+
+fn severity_from_str(severity: &str) -> AlertSeverity {
+    match severity {
+        "error" => AlertSeverity::Error,
+        "warning" => AlertSeverity::Warning,
+        "success" => AlertSeverity::Success,
+        _ => AlertSeverity::Info,
+    }
+}
+
+/// デフォルトの自動消滅までの時間
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Copy)]
+pub struct ToastContext {
+    toasts: RwSignal<Vec<Toast>>,
+    next_id: RwSignal<u64>,
+}
+
+impl ToastContext {
+    pub fn push_toast(
+        &self,
+        severity: AlertSeverity,
+        title: Option<String>,
+        message: impl Into<String>,
+        timeout: Duration,
+    ) {
+        let id = self.next_id.get_untracked();
+        self.next_id.set(id + 1);
+
+        self.toasts.update(|list| {
+            list.push(Toast {
+                id,
+                severity,
+                title,
+                message: message.into(),
+            });
+        });
+
+        let toasts = self.toasts;
+        set_timeout(
+            move || {
+                toasts.update(|list| list.retain(|t| t.id != id));
+            },
+            timeout,
+        );
+    }
+
+    pub fn dismiss(&self, id: u64) {
+        self.toasts.update(|list| list.retain(|t| t.id != id));
+    }
+}
+
+/// ツリーの上位でラップし、配下のどこからでも `use_toasts()` でトーストを積めるようにする
+#[component]
+pub fn ToastProvider(children: Children) -> impl IntoView {
+    let ctx = ToastContext {
+        toasts: RwSignal::new(Vec::new()),
+        next_id: RwSignal::new(0),
+    };
+    provide_context(ctx);
+
+    // バックエンドが発行する `app-toast` イベントを購読し、同じキューに積む
+    Effect::new(move |_| {
+        spawn_local(async move {
+            let closure = Closure::wrap(Box::new(move |event: JsValue| {
+                let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload"))
+                else {
+                    return;
+                };
+                let Ok(toast) = serde_wasm_bindgen::from_value::<AppToastPayload>(payload) else {
+                    return;
+                };
+                ctx.push_toast(
+                    severity_from_str(&toast.severity),
+                    toast.title,
+                    toast.message,
+                    DEFAULT_TIMEOUT,
+                );
+            }) as Box<dyn FnMut(JsValue)>);
+
+            let _ = listen("app-toast", closure.as_ref().unchecked_ref()).await;
+            // クロージャはリスナーが生きている間（アプリの生存期間中）解放しない
+            closure.forget();
+        });
+    });
+
+    children()
+}
+
+pub fn use_toasts() -> ToastContext {
+    use_context::<ToastContext>().expect("ToastProvider is missing from the component tree")
+}
+
+/// 画面右下に現在アクティブなトーストを積み上げて表示する
+#[component]
+pub fn ToastContainer() -> impl IntoView {
+    let ctx = use_toasts();
+
+    view! {
+        <div class="fixed bottom-4 right-4 z-50 flex flex-col gap-2 w-80">
+            <For
+                each=move || ctx.toasts.get()
+                key=|toast| toast.id
+                let:toast
+            >
+                {
+                    let id = toast.id;
+                    view! {
+                        <Alert
+                            severity=toast.severity.clone()
+                            title=toast.title.clone()
+                            on_close=move || ctx.dismiss(id)
+                        >
+                            {toast.message.clone()}
+                        </Alert>
+                    }
+                }
+            </For>
+        </div>
+    }
+}
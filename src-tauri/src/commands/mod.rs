@@ -2,10 +2,14 @@
 // lichtblickのElectron IPC通信をTauriコマンドで置換
 
 pub mod app_info;
+pub mod open;
+pub mod project;
 pub mod storage;
 pub mod system;
 
 // 各モジュールの公開コマンドを再エクスポート
 pub use app_info::*;
+pub use open::*;
+pub use project::*;
 pub use storage::*;
 pub use system::*;
@@ -0,0 +1,150 @@
+// OSのファイルマネージャ/既定アプリ/指定アプリでパスを開くコマンド
+// lichtblickの `shell.openPath` / `shell.showItemInFolder` 相当の機能をTauriで実装
+use std::path::Path;
+use std::process::Command;
+
+use crate::sandbox;
+use crate::toast;
+
+fn spawn_external(mut command: Command) -> Result<(), String> {
+    for (key, value) in sandbox::normalized_external_env() {
+        command.env(key, value);
+    }
+
+    command
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("failed to launch external process: {}", e))
+}
+
+/// 指定したパスを既定のアプリケーションで開く
+#[tauri::command]
+pub fn open_path(app: tauri::AppHandle, path: String) -> Result<(), String> {
+    let result = open_path_inner(&path);
+    if let Err(ref message) = result {
+        toast::emit_toast(&app, "error", "Open failed", message.clone());
+    }
+    result
+}
+
+fn open_path_inner(path: &str) -> Result<(), String> {
+    if !Path::new(path).exists() {
+        return Err(format!("path does not exist: {}", path));
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        spawn_external({
+            let mut cmd = Command::new("open");
+            cmd.arg(path);
+            cmd
+        })
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        spawn_external({
+            let mut cmd = Command::new("explorer");
+            cmd.arg(path);
+            cmd
+        })
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        spawn_external(linux_open_command(path))
+    }
+}
+
+/// 指定したパスをOSのファイルマネージャでハイライト表示する
+#[tauri::command]
+pub fn reveal_in_file_manager(path: String) -> Result<(), String> {
+    if !Path::new(&path).exists() {
+        return Err(format!("path does not exist: {}", path));
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        spawn_external({
+            let mut cmd = Command::new("open");
+            cmd.arg("-R").arg(&path);
+            cmd
+        })
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        spawn_external({
+            let mut cmd = Command::new("explorer");
+            cmd.arg(format!("/select,{}", path));
+            cmd
+        })
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        // gio/xdg-openはフォルダ選択表示をサポートしないため、親ディレクトリを開く
+        let parent = Path::new(&path).parent().unwrap_or_else(|| Path::new("/"));
+        spawn_external(linux_open_command(&parent.to_string_lossy()))
+    }
+}
+
+/// 指定したアプリケーションIDでパスを開く（Linuxのdesktopエントリ、macOSのbundle id等）
+#[tauri::command]
+pub fn open_with(path: String, app_id: String) -> Result<(), String> {
+    if !Path::new(&path).exists() {
+        return Err(format!("path does not exist: {}", path));
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        spawn_external({
+            let mut cmd = Command::new("open");
+            cmd.arg("-b").arg(&app_id).arg(&path);
+            cmd
+        })
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        spawn_external({
+            let mut cmd = Command::new(&app_id);
+            cmd.arg(&path);
+            cmd
+        })
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        spawn_external({
+            let mut cmd = Command::new("gtk-launch");
+            cmd.arg(&app_id).arg(&path);
+            cmd
+        })
+    }
+}
+
+/// Linux向けの「開く」コマンドを解決する。サンドボックス下ではバンドル内の
+/// xdg-open ではなくホスト側のgio/xdg-openを優先して使う。
+#[cfg(all(unix, not(target_os = "macos")))]
+fn linux_open_command(target: &str) -> Command {
+    if which_in_normalized_path("gio") {
+        let mut cmd = Command::new("gio");
+        cmd.arg("open").arg(target);
+        cmd
+    } else {
+        let mut cmd = Command::new("xdg-open");
+        cmd.arg(target);
+        cmd
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn which_in_normalized_path(bin: &str) -> bool {
+    let env = sandbox::normalized_external_env();
+    let Some(path_var) = env.get("PATH") else {
+        return false;
+    };
+
+    std::env::split_paths(path_var).any(|dir| dir.join(bin).is_file())
+}
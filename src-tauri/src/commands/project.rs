@@ -0,0 +1,96 @@
+// ファイルツリー（プロジェクトエクスプローラ）コマンド
+// `open_folder` メニューイベントはこれまでフロントエンドへの通知のみで、実際に
+// ディレクトリ内容を読む手段がなかった。std::fs を直接呼ぶ薄いコマンド層を置き、
+// 一覧・作成・リネーム・削除をフロントエンドのProjectPanelから扱えるようにする。
+// tauri-plugin-fs とそのスコープ設定は経由しない
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use crate::toast;
+
+/// ディレクトリ1階層分のエントリ。子要素はツリー側が展開時に改めて
+/// `read_dir` を呼んで遅延取得する
+#[derive(Debug, Serialize)]
+pub struct DirEntry {
+    pub name: String,
+    pub path: String,
+    pub is_dir: bool,
+}
+
+/// 指定ディレクトリ直下のエントリを一覧表示する（ディレクトリ優先、名前順）
+#[tauri::command]
+pub fn read_dir(path: String) -> Result<Vec<DirEntry>, String> {
+    let entries =
+        fs::read_dir(&path).map_err(|e| format!("failed to read directory ({}): {}", path, e))?;
+
+    let mut result: Vec<DirEntry> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let file_type = entry.file_type().ok()?;
+            Some(DirEntry {
+                name: entry.file_name().to_string_lossy().to_string(),
+                path: entry.path().to_string_lossy().to_string(),
+                is_dir: file_type.is_dir(),
+            })
+        })
+        .collect();
+
+    result.sort_by(|a, b| match (a.is_dir, b.is_dir) {
+        (true, false) => std::cmp::Ordering::Less,
+        (false, true) => std::cmp::Ordering::Greater,
+        _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+    });
+
+    Ok(result)
+}
+
+/// エントリをリネームする。親ディレクトリが異なるパスを渡せばそのまま移動として
+/// 働くため、ドラッグ&ドロップによるフォルダ間移動もこのコマンド経由で行う
+#[tauri::command]
+pub fn rename_path(from: String, to: String) -> Result<(), String> {
+    fs::rename(&from, &to).map_err(|e| format!("failed to rename ({} -> {}): {}", from, to, e))
+}
+
+/// 新しいファイル/フォルダを作成する。衝突やI/Oエラーはフロントエンドへ
+/// `Result` で返るだけでなく、ProjectPanel側が個別にハンドリングしなくても
+/// 気づけるようトーストでも知らせる
+#[tauri::command]
+pub fn create_entry(
+    app: tauri::AppHandle,
+    parent: String,
+    name: String,
+    is_dir: bool,
+) -> Result<(), String> {
+    let target = Path::new(&parent).join(&name);
+
+    let result = if target.exists() {
+        Err(format!("entry already exists: {}", target.display()))
+    } else if is_dir {
+        fs::create_dir(&target)
+            .map_err(|e| format!("failed to create directory ({}): {}", target.display(), e))
+    } else {
+        fs::File::create(&target)
+            .map(|_| ())
+            .map_err(|e| format!("failed to create file ({}): {}", target.display(), e))
+    };
+
+    result.inspect_err(|e| {
+        toast::emit_toast(&app, "error", "Create failed", e.clone());
+    })
+}
+
+/// ファイル/フォルダを削除する（フォルダは再帰的に削除）
+#[tauri::command]
+pub fn delete_entry(path: String) -> Result<(), String> {
+    let target = Path::new(&path);
+    let metadata =
+        fs::metadata(target).map_err(|e| format!("failed to stat ({}): {}", path, e))?;
+
+    if metadata.is_dir() {
+        fs::remove_dir_all(target)
+            .map_err(|e| format!("failed to delete directory ({}): {}", path, e))
+    } else {
+        fs::remove_file(target).map_err(|e| format!("failed to delete file ({}): {}", path, e))
+    }
+}
@@ -1,9 +1,15 @@
 // ローカルファイルストレージコマンド
 // lichtblickのLocalFileStorage相当の機能をTauriで実装
 use serde::Serialize;
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
-use tauri::Manager;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{Emitter, Manager, State};
+
+use crate::storage_scope::StorageScope;
+use crate::toast;
 
 /// データストア名のディレクトリ
 const DATASTORES_DIR_NAME: &str = "studio-datastores";
@@ -38,12 +44,16 @@ fn get_datastore_base_path(app: &tauri::AppHandle) -> Result<PathBuf, StorageErr
 }
 
 /// データストアのパスを確保（存在しなければ作成）
-fn ensure_datastore_path(app: &tauri::AppHandle, datastore: &str) -> Result<PathBuf, StorageError> {
-    // データストア名のバリデーション（小文字とハイフンのみ許可）
-    if !datastore.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
+fn ensure_datastore_path(
+    app: &tauri::AppHandle,
+    scope: &StorageScope,
+    datastore: &str,
+) -> Result<PathBuf, StorageError> {
+    if !scope.is_datastore_allowed(datastore) {
+        log::warn!("storage: datastore ({}) rejected by scope", datastore);
         return Err(StorageError {
-            message: format!("datastore ({}) contains invalid characters", datastore),
-            code: Some("INVALID_NAME".to_string()),
+            message: format!("datastore ({}) is not permitted by scope", datastore),
+            code: Some("SCOPE_DENIED".to_string()),
         });
     }
 
@@ -58,28 +68,114 @@ fn ensure_datastore_path(app: &tauri::AppHandle, datastore: &str) -> Result<Path
     Ok(datastore_path)
 }
 
+/// `path` が `base` の正規化済みパス配下にあることを確認する。
+/// `..` やシンボリックリンクによるデータストア外への脱出を、文字種に関係なく拒否する。
+fn assert_within_base(base: &Path, path: &Path) -> Result<(), StorageError> {
+    let canonical_base = fs::canonicalize(base).map_err(StorageError::from)?;
+    // 既に存在する対象はパス全体を正規化し、末尾のシンボリックリンクも解決する。
+    // まだ存在しない場合は親ディレクトリを正規化してからファイル名を付け直す
+    let canonical_path = match fs::canonicalize(path) {
+        Ok(resolved) => resolved,
+        Err(_) => {
+            let parent = path.parent().unwrap_or(path);
+            let canonical_parent = fs::canonicalize(parent).map_err(StorageError::from)?;
+            match path.file_name() {
+                Some(name) => canonical_parent.join(name),
+                None => canonical_parent,
+            }
+        }
+    };
+
+    if canonical_path == canonical_base || !canonical_path.starts_with(&canonical_base) {
+        log::warn!(
+            "storage: path ({}) escaped base ({})",
+            path.display(),
+            base.display()
+        );
+        return Err(StorageError {
+            message: format!("path ({}) escapes the datastore base", path.display()),
+            code: Some("PATH_ESCAPE".to_string()),
+        });
+    }
+
+    Ok(())
+}
+
 /// キーからファイルパスを生成
 fn make_file_path(
     app: &tauri::AppHandle,
+    scope: &StorageScope,
     datastore: &str,
     key: &str,
 ) -> Result<PathBuf, StorageError> {
-    // キー名のバリデーション（小文字とハイフンのみ許可）
-    if !key.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
+    if !scope.is_key_allowed(datastore, key) {
+        log::warn!(
+            "storage: key ({}) rejected by scope for datastore ({})",
+            key,
+            datastore
+        );
         return Err(StorageError {
-            message: format!("key ({}) contains invalid characters", key),
-            code: Some("INVALID_KEY".to_string()),
+            message: format!(
+                "key ({}) is not permitted by scope for datastore ({})",
+                key, datastore
+            ),
+            code: Some("SCOPE_DENIED".to_string()),
         });
     }
 
-    let datastore_path = ensure_datastore_path(app, datastore)?;
-    Ok(datastore_path.join(key))
+    let datastore_path = ensure_datastore_path(app, scope, datastore)?;
+    let file_path = datastore_path.join(key);
+    assert_within_base(&datastore_path, &file_path)?;
+
+    Ok(file_path)
+}
+
+/// `contents` を `file_path` へクラッシュセーフに書き込む。
+/// 同じディレクトリ内の一時ファイルに書いてfsyncした後、`rename`で置き換えることで
+/// 読み手は常に書き込み前後いずれかの完全な内容だけを見る。
+fn atomic_write(file_path: &Path, contents: &[u8]) -> Result<(), StorageError> {
+    let dir = file_path.parent().ok_or_else(|| StorageError {
+        message: "file path has no parent directory".to_string(),
+        code: Some("PATH_ESCAPE".to_string()),
+    })?;
+    let file_name = file_path.file_name().ok_or_else(|| StorageError {
+        message: "file path has no file name".to_string(),
+        code: Some("INVALID_KEY".to_string()),
+    })?;
+
+    let tmp_path = dir.join(format!(
+        ".{}.tmp-{}",
+        file_name.to_string_lossy(),
+        std::process::id()
+    ));
+
+    let write_result = (|| -> Result<(), StorageError> {
+        let mut tmp_file = fs::File::create(&tmp_path).map_err(StorageError::from)?;
+        tmp_file.write_all(contents).map_err(StorageError::from)?;
+        tmp_file.sync_all().map_err(StorageError::from)?;
+        fs::rename(&tmp_path, file_path).map_err(StorageError::from)
+    })();
+
+    if let Err(ref e) = write_result {
+        log::error!(
+            "storage: atomic write to ({}) failed: {}",
+            file_path.display(),
+            e.message
+        );
+        let _ = fs::remove_file(&tmp_path);
+    }
+
+    write_result
 }
 
 /// データストア内のすべてのキーを一覧表示
 #[tauri::command]
-pub fn storage_list(app: tauri::AppHandle, datastore: String) -> Result<Vec<String>, StorageError> {
-    let datastore_path = ensure_datastore_path(&app, &datastore)?;
+pub fn storage_list(
+    app: tauri::AppHandle,
+    scope: State<'_, StorageScope>,
+    datastore: String,
+) -> Result<Vec<String>, StorageError> {
+    let datastore_path = ensure_datastore_path(&app, &scope, &datastore)?;
 
     let entries = fs::read_dir(&datastore_path).map_err(StorageError::from)?;
 
@@ -100,9 +196,10 @@ pub fn storage_list(app: tauri::AppHandle, datastore: String) -> Result<Vec<Stri
 #[tauri::command]
 pub fn storage_all(
     app: tauri::AppHandle,
+    scope: State<'_, StorageScope>,
     datastore: String,
 ) -> Result<Vec<Vec<u8>>, StorageError> {
-    let datastore_path = ensure_datastore_path(&app, &datastore)?;
+    let datastore_path = ensure_datastore_path(&app, &scope, &datastore)?;
 
     let entries = fs::read_dir(&datastore_path).map_err(StorageError::from)?;
 
@@ -110,8 +207,14 @@ pub fn storage_all(
 
     for entry in entries.flatten() {
         if entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
-            if let Ok(content) = fs::read(entry.path()) {
-                results.push(content);
+            match fs::read(entry.path()) {
+                Ok(content) => results.push(content),
+                Err(e) => log::error!(
+                    "storage: failed to read ({}) in datastore ({}): {}",
+                    entry.path().display(),
+                    datastore,
+                    e
+                ),
             }
         }
     }
@@ -123,10 +226,11 @@ pub fn storage_all(
 #[tauri::command]
 pub fn storage_get(
     app: tauri::AppHandle,
+    scope: State<'_, StorageScope>,
     datastore: String,
     key: String,
 ) -> Result<Option<Vec<u8>>, StorageError> {
-    let file_path = make_file_path(&app, &datastore, &key)?;
+    let file_path = make_file_path(&app, &scope, &datastore, &key)?;
 
     match fs::read(&file_path) {
         Ok(content) => Ok(Some(content)),
@@ -139,10 +243,11 @@ pub fn storage_get(
 #[tauri::command]
 pub fn storage_get_string(
     app: tauri::AppHandle,
+    scope: State<'_, StorageScope>,
     datastore: String,
     key: String,
 ) -> Result<Option<String>, StorageError> {
-    let file_path = make_file_path(&app, &datastore, &key)?;
+    let file_path = make_file_path(&app, &scope, &datastore, &key)?;
 
     match fs::read_to_string(&file_path) {
         Ok(content) => Ok(Some(content)),
@@ -155,39 +260,82 @@ pub fn storage_get_string(
 #[tauri::command]
 pub fn storage_put(
     app: tauri::AppHandle,
+    scope: State<'_, StorageScope>,
     datastore: String,
     key: String,
     value: Vec<u8>,
 ) -> Result<(), StorageError> {
-    let file_path = make_file_path(&app, &datastore, &key)?;
-    fs::write(&file_path, value).map_err(StorageError::from)
+    let file_path = make_file_path(&app, &scope, &datastore, &key)?;
+    atomic_write(&file_path, &value).inspect_err(|e| {
+        toast::emit_toast(&app, "error", "Save failed", e.message.clone());
+    })
 }
 
 /// データを保存（UTF-8文字列）
 #[tauri::command]
 pub fn storage_put_string(
     app: tauri::AppHandle,
+    scope: State<'_, StorageScope>,
     datastore: String,
     key: String,
     value: String,
 ) -> Result<(), StorageError> {
-    let file_path = make_file_path(&app, &datastore, &key)?;
-    fs::write(&file_path, value).map_err(StorageError::from)
+    let file_path = make_file_path(&app, &scope, &datastore, &key)?;
+    atomic_write(&file_path, value.as_bytes()).inspect_err(|e| {
+        toast::emit_toast(&app, "error", "Save failed", e.message.clone());
+    })
+}
+
+/// 複数キーのデータを一括取得（バイナリ）。起動時に多数のキーを読み込む際の
+/// IPCラウンドトリップを避ける
+#[tauri::command]
+pub fn storage_get_many(
+    app: tauri::AppHandle,
+    scope: State<'_, StorageScope>,
+    datastore: String,
+    keys: Vec<String>,
+) -> Result<Vec<Option<Vec<u8>>>, StorageError> {
+    keys.iter()
+        .map(|key| {
+            let file_path = make_file_path(&app, &scope, &datastore, key)?;
+            match fs::read(&file_path) {
+                Ok(content) => Ok(Some(content)),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(StorageError::from(e)),
+            }
+        })
+        .collect()
 }
 
 /// データを削除
 #[tauri::command]
 pub fn storage_delete(
     app: tauri::AppHandle,
+    scope: State<'_, StorageScope>,
     datastore: String,
     key: String,
 ) -> Result<(), StorageError> {
-    let file_path = make_file_path(&app, &datastore, &key)?;
+    let file_path = make_file_path(&app, &scope, &datastore, &key)?;
 
     match fs::remove_file(&file_path) {
         Ok(()) => Ok(()),
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()), // 存在しない場合は成功扱い
-        Err(e) => Err(StorageError::from(e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            log::debug!(
+                "storage: delete of ({}) in datastore ({}) was a no-op, key did not exist",
+                key,
+                datastore
+            );
+            Ok(())
+        }
+        Err(e) => {
+            log::error!(
+                "storage: failed to delete ({}) in datastore ({}): {}",
+                key,
+                datastore,
+                e
+            );
+            Err(StorageError::from(e))
+        }
     }
 }
 
@@ -195,9 +343,108 @@ pub fn storage_delete(
 #[tauri::command]
 pub fn storage_exists(
     app: tauri::AppHandle,
+    scope: State<'_, StorageScope>,
     datastore: String,
     key: String,
 ) -> Result<bool, StorageError> {
-    let file_path = make_file_path(&app, &datastore, &key)?;
+    let file_path = make_file_path(&app, &scope, &datastore, &key)?;
     Ok(file_path.exists())
 }
+
+/// `storage_watch` が発行するイベントのペイロード
+#[derive(Debug, Clone, Serialize)]
+pub struct StorageChangeEvent {
+    pub datastore: String,
+    pub key: String,
+    /// "created" | "modified" | "removed"
+    pub kind: String,
+}
+
+/// `storage_watch` が開始した `notify::Watcher` をデータストアごとに1つだけ保持する。
+/// これが無いと、同じデータストアを複数ウィンドウ/複数タブが監視するたびに
+/// ウォッチャースレッドが積み上がり、`storage-changed` が呼び出し回数分重複して
+/// 発火してしまう
+#[derive(Default)]
+pub struct WatchRegistry(Mutex<HashMap<String, notify::RecommendedWatcher>>);
+
+pub fn default_watch_registry() -> WatchRegistry {
+    WatchRegistry::default()
+}
+
+/// データストア内のキーの追加・変更・削除を監視し、`storage-changed` イベントとして
+/// フロントエンドへ通知する。複数ウィンドウ/複数タブが `storage_list` をポーリング
+/// せずに同期できるようにするためのもの。同じデータストアに対しては
+/// `WatchRegistry` 内の1つの`Watcher`を使い回し、何度呼ばれても監視を多重化しない
+#[tauri::command]
+pub fn storage_watch(
+    app: tauri::AppHandle,
+    scope: State<'_, StorageScope>,
+    registry: State<'_, WatchRegistry>,
+    datastore: String,
+) -> Result<(), StorageError> {
+    let datastore_path = ensure_datastore_path(&app, &scope, &datastore)?;
+
+    let mut watchers = registry.0.lock().unwrap();
+    if watchers.contains_key(&datastore) {
+        return Ok(());
+    }
+
+    let event_app = app.clone();
+    let event_datastore = datastore.clone();
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+        let event = match result {
+            Ok(event) => event,
+            Err(e) => {
+                log::warn!("storage_watch: watcher error for ({}): {}", event_datastore, e);
+                return;
+            }
+        };
+
+        let kind = match event.kind {
+            notify::EventKind::Create(_) => "created",
+            notify::EventKind::Modify(_) => "modified",
+            notify::EventKind::Remove(_) => "removed",
+            _ => return,
+        };
+
+        for path in &event.paths {
+            let Some(key) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            // atomic_writeが使う一時ファイルの変化は通知しない
+            if key.starts_with('.') {
+                continue;
+            }
+
+            let payload = StorageChangeEvent {
+                datastore: event_datastore.clone(),
+                key: key.to_string(),
+                kind: kind.to_string(),
+            };
+            let _ = event_app.emit("storage-changed", payload);
+        }
+    })
+    .map_err(|e| StorageError {
+        message: e.to_string(),
+        code: None,
+    })?;
+
+    notify::Watcher::watch(&mut watcher, &datastore_path, notify::RecursiveMode::NonRecursive).map_err(
+        |e| StorageError {
+            message: e.to_string(),
+            code: None,
+        },
+    )?;
+
+    log::debug!("storage_watch: watching datastore ({})", datastore);
+    watchers.insert(datastore, watcher);
+
+    Ok(())
+}
+
+/// `storage_watch` で開始した監視を止める。同期が不要になったデータストアの
+/// ウォッチャーを破棄し、内部スレッド/ハンドルを解放する
+#[tauri::command]
+pub fn storage_unwatch(registry: State<'_, WatchRegistry>, datastore: String) {
+    registry.0.lock().unwrap().remove(&datastore);
+}
@@ -13,9 +13,10 @@ use tauri::Manager;
 /// ```
 #[tauri::command]
 pub fn get_home_path() -> Result<String, String> {
-    dirs::home_dir()
-        .map(|p| p.to_string_lossy().to_string())
-        .ok_or_else(|| "Could not find home directory".to_string())
+    dirs::home_dir().map(|p| p.to_string_lossy().to_string()).ok_or_else(|| {
+        log::error!("system: could not determine home directory");
+        "Could not find home directory".to_string()
+    })
 }
 
 /// ユーザーデータディレクトリのパスを取得
@@ -26,10 +27,10 @@ pub fn get_home_path() -> Result<String, String> {
 /// Linux: ~/.local/share/{app_name}
 #[tauri::command]
 pub fn get_user_data_path(app: tauri::AppHandle) -> Result<String, String> {
-    app.path()
-        .app_data_dir()
-        .map(|p| p.to_string_lossy().to_string())
-        .map_err(|e| e.to_string())
+    app.path().app_data_dir().map(|p| p.to_string_lossy().to_string()).map_err(|e| {
+        log::error!("system: failed to resolve app data dir: {}", e);
+        e.to_string()
+    })
 }
 
 /// 設定ディレクトリのパスを取得
@@ -39,28 +40,28 @@ pub fn get_user_data_path(app: tauri::AppHandle) -> Result<String, String> {
 /// Linux: ~/.config/{app_name}
 #[tauri::command]
 pub fn get_config_path(app: tauri::AppHandle) -> Result<String, String> {
-    app.path()
-        .app_config_dir()
-        .map(|p| p.to_string_lossy().to_string())
-        .map_err(|e| e.to_string())
+    app.path().app_config_dir().map(|p| p.to_string_lossy().to_string()).map_err(|e| {
+        log::error!("system: failed to resolve app config dir: {}", e);
+        e.to_string()
+    })
 }
 
 /// キャッシュディレクトリのパスを取得
 #[tauri::command]
 pub fn get_cache_path(app: tauri::AppHandle) -> Result<String, String> {
-    app.path()
-        .app_cache_dir()
-        .map(|p| p.to_string_lossy().to_string())
-        .map_err(|e| e.to_string())
+    app.path().app_cache_dir().map(|p| p.to_string_lossy().to_string()).map_err(|e| {
+        log::error!("system: failed to resolve app cache dir: {}", e);
+        e.to_string()
+    })
 }
 
 /// ログディレクトリのパスを取得
 #[tauri::command]
 pub fn get_log_path(app: tauri::AppHandle) -> Result<String, String> {
-    app.path()
-        .app_log_dir()
-        .map(|p| p.to_string_lossy().to_string())
-        .map_err(|e| e.to_string())
+    app.path().app_log_dir().map(|p| p.to_string_lossy().to_string()).map_err(|e| {
+        log::error!("system: failed to resolve app log dir: {}", e);
+        e.to_string()
+    })
 }
 
 /// 環境変数を取得
@@ -72,9 +73,10 @@ pub fn get_env_var(name: String) -> Option<String> {
 /// ホスト名を取得
 #[tauri::command]
 pub fn get_hostname() -> Result<String, String> {
-    hostname::get()
-        .map(|h| h.to_string_lossy().to_string())
-        .map_err(|e| e.to_string())
+    hostname::get().map(|h| h.to_string_lossy().to_string()).map_err(|e| {
+        log::error!("system: failed to resolve hostname: {}", e);
+        e.to_string()
+    })
 }
 
 /// プロセスIDを取得
@@ -104,3 +106,54 @@ pub fn get_os_info() -> OsInfo {
         pid: std::process::id(),
     }
 }
+
+/// ログファイルから読み出した1エントリ
+#[derive(Debug, Serialize)]
+pub struct LogEntry {
+    pub timestamp_ms: u64,
+    pub level: String,
+    pub target: String,
+    pub message: String,
+}
+
+/// `get_log_path()` が指すログファイルの末尾を読み、直近 `limit` 件をフロントエンドの
+/// 診断ビュー向けに構造化して返す
+#[tauri::command]
+pub fn get_recent_logs(app: tauri::AppHandle, limit: usize) -> Result<Vec<LogEntry>, String> {
+    let log_dir = app.path().app_log_dir().map_err(|e| e.to_string())?;
+    let log_path = crate::logging::log_path(&log_dir);
+
+    let content = match std::fs::read_to_string(&log_path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            log::error!("system: failed to read log file ({}): {}", log_path.display(), e);
+            return Err(e.to_string());
+        }
+    };
+
+    let mut entries: Vec<LogEntry> = content
+        .lines()
+        .rev()
+        .take(limit)
+        .filter_map(parse_log_line)
+        .collect();
+    entries.reverse();
+
+    Ok(entries)
+}
+
+fn parse_log_line(line: &str) -> Option<LogEntry> {
+    let mut parts = line.splitn(4, ' ');
+    let timestamp_ms = parts.next()?.parse().ok()?;
+    let level = parts.next()?.to_string();
+    let target = parts.next()?.trim_end_matches(':').to_string();
+    let message = parts.next().unwrap_or_default().to_string();
+
+    Some(LogEntry {
+        timestamp_ms,
+        level,
+        target,
+        message,
+    })
+}
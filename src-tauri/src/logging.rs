@@ -0,0 +1,83 @@
+// `log` クレートをファイルシンクへ配線するロギング基盤
+//
+// storage/system コマンドの失敗は以前はオペイクな `StorageError`/`String` としてしか
+// 表面化せず、ディスク上には何も残らなかった。`get_log_path()` が指すディレクトリに
+// ログファイルを置き、`log::error!`/`log::warn!`/`log::debug!` の呼び出しを
+// そこへ集約することで、障害発生時にアプリ内診断（`get_recent_logs`）やユーザーからの
+// ログ提出で調査できるようにする。
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const LOG_FILE_NAME: &str = "shinsei.log";
+
+struct FileLogger {
+    file: Mutex<std::fs::File>,
+}
+
+impl log::Log for FileLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0);
+
+        let line = format!(
+            "{} {} {}: {}\n",
+            timestamp_ms,
+            record.level(),
+            record.target(),
+            record.args()
+        );
+
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.write_all(line.as_bytes());
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.flush();
+        }
+    }
+}
+
+/// ログディレクトリ内のログファイルへのパス
+pub fn log_path(log_dir: &Path) -> PathBuf {
+    log_dir.join(LOG_FILE_NAME)
+}
+
+/// `log` クレートのグローバルロガーを `log_dir` 配下のファイルシンクとして初期化する。
+/// アプリの `setup` から一度だけ呼び出す想定
+pub fn init(log_dir: &Path) {
+    if std::fs::create_dir_all(log_dir).is_err() {
+        return;
+    }
+
+    let Ok(file) = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path(log_dir))
+    else {
+        return;
+    };
+
+    let logger = FileLogger {
+        file: Mutex::new(file),
+    };
+
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        log::set_max_level(log::LevelFilter::Debug);
+    }
+}
@@ -0,0 +1,214 @@
+// メニューバーとコマンドパレットが共有するコマンドレジストリ
+//
+// 以前は `create_menu` がメニューIDをハードコードし、`on_menu_event` の巨大な match で
+// それぞれのIDを個別に処理していた。コマンドパレット（フロントエンド）が同じコマンド
+// 一覧をファジー検索できるよう、メニュー項目を1つのテーブルとして定義し、
+// メニュー構築・`on_menu_event`・`list_commands`/`invoke_command` の全てがこのテーブルを
+// 参照する構成に揃える。
+
+use serde::Serialize;
+use tauri::{
+    menu::{Menu, MenuItem, PredefinedMenuItem, Submenu},
+    Emitter, Manager,
+};
+
+/// メニュー/コマンドパレットの両方から参照される1コマンドの定義
+pub struct CommandSpec {
+    pub id: &'static str,
+    pub label: &'static str,
+    pub accelerator: Option<&'static str>,
+}
+
+const FILE_COMMANDS: &[CommandSpec] = &[
+    CommandSpec { id: "open_file", label: "Open File...", accelerator: Some("CmdOrCtrl+O") },
+    CommandSpec { id: "open_folder", label: "Open Folder...", accelerator: Some("CmdOrCtrl+Shift+O") },
+    CommandSpec { id: "save", label: "Save", accelerator: Some("CmdOrCtrl+S") },
+    CommandSpec { id: "save_as", label: "Save As...", accelerator: Some("CmdOrCtrl+Shift+S") },
+    CommandSpec { id: "close", label: "Close Window", accelerator: Some("CmdOrCtrl+W") },
+];
+
+const VIEW_COMMANDS: &[CommandSpec] = &[
+    CommandSpec { id: "toggle_fullscreen", label: "Toggle Fullscreen", accelerator: Some("F11") },
+    CommandSpec { id: "zoom_in", label: "Zoom In", accelerator: Some("CmdOrCtrl+Plus") },
+    CommandSpec { id: "zoom_out", label: "Zoom Out", accelerator: Some("CmdOrCtrl+Minus") },
+    CommandSpec { id: "reset_zoom", label: "Reset Zoom", accelerator: Some("CmdOrCtrl+0") },
+];
+
+const HELP_COMMANDS: &[CommandSpec] = &[
+    CommandSpec { id: "documentation", label: "Documentation", accelerator: None },
+    CommandSpec { id: "about", label: "About Shinsei", accelerator: None },
+];
+
+/// `on_menu_event` 経由で分岐されるアプリ固有コマンドの全体
+fn all_commands() -> impl Iterator<Item = &'static CommandSpec> {
+    FILE_COMMANDS.iter().chain(VIEW_COMMANDS.iter()).chain(HELP_COMMANDS.iter())
+}
+
+pub fn create_menu(app: &tauri::AppHandle) -> Result<Menu<tauri::Wry>, tauri::Error> {
+    let file_items: Vec<MenuItem<tauri::Wry>> = FILE_COMMANDS
+        .iter()
+        .map(|cmd| MenuItem::with_id(app, cmd.id, cmd.label, true, cmd.accelerator))
+        .collect::<Result<_, _>>()?;
+
+    // File メニュー（アプリ固有コマンドの合間にセパレータとQuitを挟む）
+    let file_menu = Submenu::with_items(
+        app,
+        "File",
+        true,
+        &[
+            &file_items[0],
+            &file_items[1],
+            &PredefinedMenuItem::separator(app)?,
+            &file_items[2],
+            &file_items[3],
+            &PredefinedMenuItem::separator(app)?,
+            &file_items[4],
+            &PredefinedMenuItem::quit(app, Some("Quit"))?,
+        ],
+    )?;
+
+    // Edit メニュー（OS標準アクションのみなのでコマンドテーブルには載せない）
+    let edit_menu = Submenu::with_items(
+        app,
+        "Edit",
+        true,
+        &[
+            &PredefinedMenuItem::undo(app, Some("Undo"))?,
+            &PredefinedMenuItem::redo(app, Some("Redo"))?,
+            &PredefinedMenuItem::separator(app)?,
+            &PredefinedMenuItem::cut(app, Some("Cut"))?,
+            &PredefinedMenuItem::copy(app, Some("Copy"))?,
+            &PredefinedMenuItem::paste(app, Some("Paste"))?,
+            &PredefinedMenuItem::select_all(app, Some("Select All"))?,
+        ],
+    )?;
+
+    let view_items: Vec<MenuItem<tauri::Wry>> = VIEW_COMMANDS
+        .iter()
+        .map(|cmd| MenuItem::with_id(app, cmd.id, cmd.label, true, cmd.accelerator))
+        .collect::<Result<_, _>>()?;
+    let view_menu = Submenu::with_items(
+        app,
+        "View",
+        true,
+        &view_items.iter().collect::<Vec<_>>(),
+    )?;
+
+    let help_items: Vec<MenuItem<tauri::Wry>> = HELP_COMMANDS
+        .iter()
+        .map(|cmd| MenuItem::with_id(app, cmd.id, cmd.label, true, cmd.accelerator))
+        .collect::<Result<_, _>>()?;
+    let help_menu = Submenu::with_items(
+        app,
+        "Help",
+        true,
+        &help_items.iter().collect::<Vec<_>>(),
+    )?;
+
+    // メニューバーを構築
+    Menu::with_items(app, &[&file_menu, &edit_menu, &view_menu, &help_menu])
+}
+
+/// メニューIDごとの実際の処理。`on_menu_event` とコマンドパレットの
+/// `invoke_command` の両方から呼ばれる
+pub fn dispatch_command(app: &tauri::AppHandle, id: &str) {
+    match id {
+        "open_file" => {
+            log::debug!("menu: Open File clicked");
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit("menu-event", "open_file");
+            }
+        }
+        "open_folder" => {
+            log::debug!("menu: Open Folder clicked");
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit("menu-event", "open_folder");
+            }
+        }
+        "save" => {
+            log::debug!("menu: Save clicked");
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit("menu-event", "save");
+            }
+        }
+        "save_as" => {
+            log::debug!("menu: Save As clicked");
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit("menu-event", "save_as");
+            }
+        }
+        "close" => {
+            log::debug!("menu: Close Window clicked");
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.close();
+            }
+        }
+        "toggle_fullscreen" => {
+            log::debug!("menu: Toggle Fullscreen clicked");
+            if let Some(window) = app.get_webview_window("main") {
+                if let Ok(is_fullscreen) = window.is_fullscreen() {
+                    let _ = window.set_fullscreen(!is_fullscreen);
+                }
+            }
+        }
+        "zoom_in" => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit("menu-event", "zoom_in");
+            }
+        }
+        "zoom_out" => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit("menu-event", "zoom_out");
+            }
+        }
+        "reset_zoom" => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit("menu-event", "reset_zoom");
+            }
+        }
+        "documentation" => {
+            let _ = tauri_plugin_opener::OpenerExt::opener(app)
+                .open_url("https://github.com/lichtblick-suite/lichtblick", None::<&str>);
+        }
+        "about" => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.emit("menu-event", "about");
+            }
+        }
+        _ => {
+            log::warn!("menu: unknown command id ({})", id);
+        }
+    }
+}
+
+/// コマンドパレットに返す1コマンド分の情報
+#[derive(Debug, Serialize)]
+pub struct CommandInfo {
+    pub id: String,
+    pub label: String,
+    pub accelerator: Option<String>,
+}
+
+/// 登録済みの全コマンドを返す。フロントエンドのコマンドパレットはこれを
+/// ファジー検索で絞り込む
+#[tauri::command]
+pub fn list_commands() -> Vec<CommandInfo> {
+    all_commands()
+        .map(|cmd| CommandInfo {
+            id: cmd.id.to_string(),
+            label: cmd.label.to_string(),
+            accelerator: cmd.accelerator.map(|a| a.to_string()),
+        })
+        .collect()
+}
+
+/// コマンドパレットから選択されたコマンドを、メニュークリック時と同じ経路で実行する
+#[tauri::command]
+pub fn invoke_command(app: tauri::AppHandle, id: String) -> Result<(), String> {
+    if !all_commands().any(|cmd| cmd.id == id) {
+        return Err(format!("unknown command id ({})", id));
+    }
+
+    dispatch_command(&app, &id);
+    Ok(())
+}
@@ -0,0 +1,132 @@
+// Linuxサンドボイ環境（AppImage/Flatpak/Snap）検出と環境変数の正規化
+//
+// バンドルされたアプリ（AppImage/Flatpak/Snap）はランタイムが `PATH` や
+// `LD_LIBRARY_PATH` などをバンドル内のディレクトリに書き換えて起動する。
+// その状態のまま外部プロセス（ファイルマネージャ等）を起動すると、
+// 外部プロセスがバンドル内のライブラリ/バイナリを誤って読み込みクラッシュする。
+// そのためプロセス起動時点の環境を一度スナップショットしておき、
+// 子プロセスを spawn する際はバンドル由来のエントリを取り除いた環境を渡す。
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// 検出されたサンドボックスの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandboxKind {
+    None,
+    AppImage,
+    Flatpak,
+    Snap,
+}
+
+impl SandboxKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SandboxKind::None => "none",
+            SandboxKind::AppImage => "appimage",
+            SandboxKind::Flatpak => "flatpak",
+            SandboxKind::Snap => "snap",
+        }
+    }
+}
+
+/// プロセス起動時点の環境変数スナップショット
+static ENV_SNAPSHOT: OnceLock<HashMap<String, String>> = OnceLock::new();
+
+/// `run()` の最初で一度だけ呼び出し、現在の環境変数を保存する
+pub fn capture_env_snapshot() {
+    ENV_SNAPSHOT.get_or_init(|| std::env::vars().collect());
+}
+
+fn snapshot() -> &'static HashMap<String, String> {
+    ENV_SNAPSHOT.get_or_init(|| std::env::vars().collect())
+}
+
+pub fn is_appimage() -> bool {
+    snapshot().contains_key("APPIMAGE")
+}
+
+pub fn is_flatpak() -> bool {
+    snapshot().contains_key("FLATPAK_ID") || Path::new("/.flatpak-info").exists()
+}
+
+pub fn is_snap() -> bool {
+    snapshot().contains_key("SNAP")
+}
+
+pub fn detect_sandbox() -> SandboxKind {
+    if is_flatpak() {
+        SandboxKind::Flatpak
+    } else if is_snap() {
+        SandboxKind::Snap
+    } else if is_appimage() {
+        SandboxKind::AppImage
+    } else {
+        SandboxKind::None
+    }
+}
+
+/// バンドルのルートディレクトリ（AppImageのマウント先 `$APPDIR`、Snapの `$SNAP`、
+/// Flatpakの `/app`）を、正規化の除外対象として返す
+fn bundle_dirs() -> Vec<PathBuf> {
+    let snap = snapshot();
+    let mut dirs = Vec::new();
+    if let Some(appdir) = snap.get("APPDIR") {
+        dirs.push(PathBuf::from(appdir));
+    }
+    if let Some(snap_dir) = snap.get("SNAP") {
+        dirs.push(PathBuf::from(snap_dir));
+    }
+    if snap.contains_key("FLATPAK_ID") {
+        // Flatpakはアプリのランタイムを常に /app にマウントする
+        dirs.push(PathBuf::from("/app"));
+    }
+    dirs
+}
+
+/// `:` 区切りの環境変数を正規化する:
+/// - 空エントリを除去
+/// - canonicalize した結果がバンドルディレクトリ配下にあるエントリを除去
+/// - 最初の出現を優先して重複を除去
+pub fn normalize_pathlist(value: &str) -> String {
+    let bundles = bundle_dirs();
+    let mut seen = std::collections::HashSet::new();
+    let mut out = Vec::new();
+
+    for entry in value.split(':') {
+        if entry.is_empty() {
+            continue;
+        }
+
+        let canonical = std::fs::canonicalize(entry).unwrap_or_else(|_| PathBuf::from(entry));
+        if bundles.iter().any(|b| canonical.starts_with(b)) {
+            continue;
+        }
+
+        if seen.insert(entry.to_string()) {
+            out.push(entry.to_string());
+        }
+    }
+
+    out.join(":")
+}
+
+/// バンドル環境を取り除いた、外部プロセスへ渡すための環境変数一覧を返す
+pub fn normalized_external_env() -> HashMap<String, String> {
+    const PATHLIST_VARS: &[&str] = &[
+        "PATH",
+        "LD_LIBRARY_PATH",
+        "GTK_PATH",
+        "GST_PLUGIN_SYSTEM_PATH",
+        "XDG_DATA_DIRS",
+    ];
+
+    let mut env = snapshot().clone();
+    for var in PATHLIST_VARS {
+        if let Some(value) = env.get(*var).cloned() {
+            env.insert(var.to_string(), normalize_pathlist(&value));
+        }
+    }
+    env
+}
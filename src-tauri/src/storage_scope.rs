@@ -0,0 +1,83 @@
+// ストレージデータストア/キーへのアクセスを制御するスコープ設定
+//
+// Tauriのasset-protocol/ACLスコープにならい、データストアごとに許可するキーを
+// globパターンのallow/denyリストで宣言する。未登録のデータストアへのアクセスは
+// 既定で拒否される。
+
+use std::collections::HashMap;
+
+/// 単一のデータストアに対するキーの許可/拒否ルール
+#[derive(Debug, Clone)]
+pub struct DatastoreRule {
+    allow: Vec<String>,
+    deny: Vec<String>,
+}
+
+impl DatastoreRule {
+    pub fn new(allow: Vec<String>, deny: Vec<String>) -> Self {
+        Self { allow, deny }
+    }
+
+    pub fn allow_all() -> Self {
+        Self::new(vec!["*".to_string()], Vec::new())
+    }
+
+    fn matches(&self, key: &str) -> bool {
+        if self.deny.iter().any(|pattern| glob_match(pattern, key)) {
+            return false;
+        }
+        self.allow.iter().any(|pattern| glob_match(pattern, key))
+    }
+}
+
+/// アプリ全体で共有されるデータストアスコープ設定。`AppState` として登録する。
+#[derive(Debug, Clone, Default)]
+pub struct StorageScope {
+    datastores: HashMap<String, DatastoreRule>,
+}
+
+impl StorageScope {
+    pub fn register(mut self, datastore: impl Into<String>, rule: DatastoreRule) -> Self {
+        self.datastores.insert(datastore.into(), rule);
+        self
+    }
+
+    pub fn is_datastore_allowed(&self, datastore: &str) -> bool {
+        self.datastores.contains_key(datastore)
+    }
+
+    pub fn is_key_allowed(&self, datastore: &str, key: &str) -> bool {
+        self.datastores
+            .get(datastore)
+            .map(|rule| rule.matches(key))
+            .unwrap_or(false)
+    }
+}
+
+/// このアプリが実際に使用するデータストアの既定スコープ
+pub fn default_scope() -> StorageScope {
+    StorageScope::default()
+        .register("layouts", DatastoreRule::allow_all())
+        .register("user-settings", DatastoreRule::allow_all())
+        .register("extensions", DatastoreRule::allow_all())
+        .register("recently-opened", DatastoreRule::allow_all())
+}
+
+/// `*` と `?` のみをサポートする単純なglobマッチャー
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    glob_match_inner(&pattern, &text)
+}
+
+fn glob_match_inner(pattern: &[char], text: &[char]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some('*') => {
+            glob_match_inner(&pattern[1..], text)
+                || (!text.is_empty() && glob_match_inner(pattern, &text[1..]))
+        }
+        Some('?') => !text.is_empty() && glob_match_inner(&pattern[1..], &text[1..]),
+        Some(c) => text.first() == Some(c) && glob_match_inner(&pattern[1..], &text[1..]),
+    }
+}
@@ -0,0 +1,28 @@
+// フロントエンドのトースト通知キューへ、バックエンド側の失敗を知らせるための薄い橋渡し
+// `ToastProvider` は `app-toast` イベントを購読しており、ここから発行したペイロードが
+// そのままキューに積まれる
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AppToastPayload {
+    /// "error" | "warning" | "info" | "success"
+    pub severity: &'static str,
+    pub title: Option<String>,
+    pub message: String,
+}
+
+/// メインウィンドウへ `app-toast` イベントを発行する。ウィンドウが存在しない場合は
+/// 何もしない（起動直後やシャットダウン中の早期/遅延呼び出しを静かに無視する）
+pub fn emit_toast(app: &AppHandle, severity: &'static str, title: impl Into<String>, message: impl Into<String>) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.emit(
+            "app-toast",
+            AppToastPayload {
+                severity,
+                title: Some(title.into()),
+                message: message.into(),
+            },
+        );
+    }
+}
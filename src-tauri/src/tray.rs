@@ -0,0 +1,73 @@
+// システムトレイへの最小化
+//
+// 以前はメインウィンドウを閉じる（メニューの"close"、OSの閉じるボタンどちらも）と
+// アプリ全体が終了していた。トレイアイコンを追加し、ウィンドウを閉じた場合は
+// 非表示にするだけに留め、終了はトレイメニューまたはファイルメニューの明示的な"Quit"からのみ
+// 行えるようにする。
+
+use tauri::{
+    menu::{Menu, MenuItem, PredefinedMenuItem},
+    tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
+    Emitter, Manager,
+};
+
+const TRAY_SHOW_ID: &str = "tray_show";
+const TRAY_HIDE_ID: &str = "tray_hide";
+
+pub fn create_tray(app: &tauri::AppHandle) -> tauri::Result<()> {
+    let show = MenuItem::with_id(app, TRAY_SHOW_ID, "Show", true, None::<&str>)?;
+    let hide = MenuItem::with_id(app, TRAY_HIDE_ID, "Hide", true, None::<&str>)?;
+    let quit = PredefinedMenuItem::quit(app, Some("Quit"))?;
+    let menu = Menu::with_items(app, &[&show, &hide, &PredefinedMenuItem::separator(app)?, &quit])?;
+
+    let mut builder = TrayIconBuilder::new().menu(&menu).show_menu_on_left_click(false);
+    if let Some(icon) = app.default_window_icon().cloned() {
+        builder = builder.icon(icon);
+    }
+
+    builder
+        .on_menu_event(|app, event| match event.id().as_ref() {
+            TRAY_SHOW_ID => show_main_window(app),
+            TRAY_HIDE_ID => hide_main_window(app),
+            _ => {}
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                toggle_main_window(tray.app_handle());
+            }
+        })
+        .build(app)?;
+
+    Ok(())
+}
+
+fn show_main_window(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+        // フロントエンドへ再表示を通知し、UI状態を必要に応じて再同期させる
+        let _ = window.emit("menu-event", "window-shown");
+    }
+}
+
+fn hide_main_window(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.hide();
+    }
+}
+
+fn toggle_main_window(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        match window.is_visible() {
+            Ok(true) => {
+                let _ = window.hide();
+            }
+            _ => show_main_window(app),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+// Breadcrumbs Component
+// エディタ上部の現在位置ストリップ。ワークスペースルートからの相対パスと、
+// カーソル位置のシンボルスコープを並べて表示する。パスセグメントのクリックは
+// `menu-event` 相当のナビゲーション通知として親へ伝え、シンボルセグメントの
+// クリックは ContextMenu primitive を使って兄弟シンボル一覧をドロップダウン表示する
+
+use leptos::prelude::*;
+
+use crate::components::context_menu::{ContextMenu, ContextMenuItem};
+
+/// エディタのカーソル位置から供給される、シンボルスコープ1段分の情報
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymbolSegment {
+    pub name: String,
+    /// ドロップダウンに並べる、同階層にある兄弟シンボル名
+    pub siblings: Vec<String>,
+}
+
+/// ルートからの相対パスを、セグメントごとの累積パスに分解する。
+/// 例えば `src/app/src` なら `["src", "src/app", "src/app/src"]` になる。
+/// セグメント名ではなく累積パスそのものをキー/ナビゲーション先として扱うことで、
+/// `src/app/src` のようにセグメント名が重複するパスでも `<For>` のキーが衝突したり
+/// 誤った祖先へナビゲートしたりしない
+fn relative_segments(root: &str, active_path: &str) -> Vec<String> {
+    let relative = active_path.strip_prefix(root).unwrap_or(active_path);
+    let mut cumulative = String::new();
+    relative
+        .trim_start_matches('/')
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            if cumulative.is_empty() {
+                cumulative = s.to_string();
+            } else {
+                cumulative = format!("{}/{}", cumulative, s);
+            }
+            cumulative.clone()
+        })
+        .collect()
+}
+
+#[component]
+pub fn Breadcrumbs(
+    /// プロジェクトエクスプローラが開いているワークスペースのルート
+    #[prop(into)]
+    root: Signal<Option<String>>,
+    /// アクティブなドキュメントのフルパス（TabBarのアクティブタブと連動させる）
+    #[prop(into)]
+    active_path: Signal<Option<String>>,
+    /// カーソル位置から見たシンボルスコープ（外側→内側の順）
+    #[prop(into)]
+    symbol_path: Signal<Vec<SymbolSegment>>,
+    /// パスセグメントがクリックされたときに、ルートからの相対パスを渡す
+    #[prop(optional, into)]
+    on_navigate: Option<Callback<String>>,
+    /// ドロップダウンから兄弟シンボルが選択されたときに渡す
+    #[prop(optional, into)]
+    on_symbol_select: Option<Callback<String>>,
+) -> impl IntoView {
+    let (menu_open, set_menu_open) = signal(false);
+    let (menu_x, set_menu_x) = signal(0.0f64);
+    let (menu_y, set_menu_y) = signal(0.0f64);
+    let (menu_siblings, set_menu_siblings) = signal(Vec::<String>::new());
+
+    let path_segments = Memo::new(move |_| match (root.get(), active_path.get()) {
+        (Some(root), Some(active_path)) => relative_segments(&root, &active_path),
+        _ => Vec::new(),
+    });
+
+    view! {
+        <div class="flex items-center gap-1 px-3 py-1.5 bg-background-paper border-b border-grey-600 text-sm text-text-secondary overflow-x-auto whitespace-nowrap">
+            <For
+                each=move || path_segments.get()
+                key=|cumulative_path| cumulative_path.clone()
+                let:cumulative_path
+            >
+                {
+                    // この要素自体がルートからの累積パスなので、position()で
+                    // 同名セグメントを探し直す必要がない
+                    let label = cumulative_path
+                        .rsplit('/')
+                        .next()
+                        .unwrap_or(&cumulative_path)
+                        .to_string();
+                    let navigate = move |_| {
+                        if let Some(cb) = on_navigate {
+                            cb.run(cumulative_path.clone());
+                        }
+                    };
+
+                    view! {
+                        <span class="text-text-secondary">"/"</span>
+                        <button
+                            class="hover:text-text-primary hover:underline cursor-pointer"
+                            on:click=navigate
+                        >
+                            {label}
+                        </button>
+                    }
+                }
+            </For>
+
+            <For
+                each=move || symbol_path.get()
+                key=|segment| segment.name.clone()
+                let:segment
+            >
+                {
+                    let siblings = segment.siblings.clone();
+                    let open_dropdown = move |ev: leptos::ev::MouseEvent| {
+                        ev.stop_propagation();
+                        set_menu_x.set(ev.client_x() as f64);
+                        set_menu_y.set(ev.client_y() as f64);
+                        set_menu_siblings.set(siblings.clone());
+                        set_menu_open.set(true);
+                    };
+
+                    view! {
+                        <span class="text-text-secondary">"›"</span>
+                        <button
+                            class="text-primary-500 hover:underline cursor-pointer"
+                            on:click=open_dropdown
+                        >
+                            {segment.name.clone()}
+                        </button>
+                    }
+                }
+            </For>
+        </div>
+
+        <ContextMenu open=menu_open x=menu_x y=menu_y on_close=set_menu_open>
+            <For
+                each=move || menu_siblings.get()
+                key=|name| name.clone()
+                let:name
+            >
+                {
+                    let label = name.clone();
+                    let select = move |_| {
+                        if let Some(cb) = on_symbol_select {
+                            cb.run(name.clone());
+                        }
+                        set_menu_open.set(false);
+                    };
+                    view! { <ContextMenuItem label=label on_click=Callback::new(select) /> }
+                }
+            </For>
+        </ContextMenu>
+    }
+}
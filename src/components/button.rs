@@ -1,8 +1,19 @@
 // Button Component
 // MUI ButtonのLeptos実装
 
+use std::time::Duration;
+
+use leptos::leptos_dom::helpers::{set_timeout_with_handle, TimeoutHandle};
 use leptos::prelude::*;
 
+/// ポインタの押下状態。Trezorの `Button` コンポーネントの押下ステートマシンを移植したもの
+#[derive(Clone, Copy, PartialEq)]
+enum PressState {
+    Initial,
+    Pressed,
+    Released,
+}
+
 #[derive(Clone, PartialEq)]
 pub enum ButtonVariant {
     Contained,
@@ -29,6 +40,15 @@ pub enum ButtonSize {
     Large,
 }
 
+/// `ButtonGroup` の内側にいる `Button` へ共有の見た目設定を伝える。個々の
+/// `Button` が `variant`/`color`/`size` を明示しなかった場合のフォールバック先
+#[derive(Clone)]
+pub(crate) struct ButtonGroupContext {
+    pub variant: Option<ButtonVariant>,
+    pub color: Option<ButtonColor>,
+    pub size: Option<ButtonSize>,
+}
+
 #[component]
 pub fn Button(
     children: Children,
@@ -37,15 +57,92 @@ pub fn Button(
     #[prop(optional)] size: Option<ButtonSize>,
     #[prop(optional)] disabled: Option<bool>,
     #[prop(optional)] full_width: Option<bool>,
+    #[prop(optional)] on_press: Option<Callback<()>>,
+    #[prop(optional)] on_release: Option<Callback<()>>,
     #[prop(optional)] on_click: Option<Callback<()>>,
+    #[prop(optional)] on_long_press: Option<Callback<()>>,
+    /// 設定すると、押下がこの時間を超えて続いた際に `on_long_press` を発火し、
+    /// 後続の `on_click` を抑制する
+    #[prop(optional)] long_press: Option<Duration>,
+    /// 本文の前に表示するアイコン（`ButtonSize` に応じた間隔が自動で付く）
+    #[prop(optional)] start_icon: Option<AnyView>,
+    /// 本文の後に表示するアイコン
+    #[prop(optional)] end_icon: Option<AnyView>,
     #[prop(optional, into)] class: Option<String>,
 ) -> impl IntoView {
-    let variant = variant.unwrap_or(ButtonVariant::Contained);
-    let color = color.unwrap_or(ButtonColor::Primary);
-    let size = size.unwrap_or(ButtonSize::Medium);
+    let group = use_context::<ButtonGroupContext>();
+    let variant = variant
+        .or_else(|| group.as_ref().and_then(|g| g.variant.clone()))
+        .unwrap_or(ButtonVariant::Contained);
+    let color = color
+        .or_else(|| group.as_ref().and_then(|g| g.color.clone()))
+        .unwrap_or(ButtonColor::Primary);
+    let size = size
+        .or_else(|| group.as_ref().and_then(|g| g.size.clone()))
+        .unwrap_or(ButtonSize::Medium);
     let disabled = disabled.unwrap_or(false);
     let full_width = full_width.unwrap_or(false);
 
+    let (press_state, set_press_state) = signal(PressState::Initial);
+    let timer_handle = StoredValue::new(None::<TimeoutHandle>);
+    let long_press_handled = StoredValue::new(false);
+
+    let clear_timer = move || {
+        timer_handle.update_value(|handle| {
+            if let Some(handle) = handle.take() {
+                handle.clear();
+            }
+        });
+    };
+
+    let start_press = move || {
+        if disabled {
+            return;
+        }
+        set_press_state.set(PressState::Pressed);
+        long_press_handled.set_value(false);
+        if let Some(cb) = on_press {
+            cb.run(());
+        }
+
+        if let Some(duration) = long_press {
+            let handle = set_timeout_with_handle(
+                move || {
+                    long_press_handled.set_value(true);
+                    if let Some(cb) = on_long_press {
+                        cb.run(());
+                    }
+                },
+                duration,
+            )
+            .ok();
+            timer_handle.set_value(handle);
+        }
+    };
+
+    let end_press = move || {
+        if press_state.get_untracked() != PressState::Pressed {
+            return;
+        }
+        clear_timer();
+        set_press_state.set(PressState::Released);
+        if let Some(cb) = on_release {
+            cb.run(());
+        }
+        if !long_press_handled.get_value() {
+            if let Some(cb) = on_click {
+                cb.run(());
+            }
+        }
+    };
+
+    let cancel_press = move || {
+        clear_timer();
+        set_press_state.set(PressState::Initial);
+    };
+
+    on_cleanup(move || clear_timer());
+
     // ベースクラス
     let mut base_classes = vec![
         "inline-flex".to_string(),
@@ -85,8 +182,42 @@ pub fn Button(
         base_classes.push("w-full".to_string());
     }
 
-    // バリアントとカラーの組み合わせ
-    let variant_color_classes = match (&variant, &color) {
+    base_classes.push(variant_color_classes(&variant, &color));
+
+    // カスタムクラスの追加
+    if let Some(custom_class) = class {
+        base_classes.push(custom_class);
+    }
+
+    let class_string = base_classes.join(" ");
+    let icon_gap = icon_gap_class(&size);
+
+    view! {
+        <button
+            type="button"
+            class=class_string
+            disabled=disabled
+            // Pointer Eventsはタッチ/マウス/ペンを統一的に扱うため、タッチ用の
+            // touchstart/touchendを別途bindすると同じ押下でstart_press/end_press
+            // が二重発火し、long_pressタイマーが多重に張られてon_long_pressも
+            // 二度呼ばれてしまう。タッチは pointerdown/pointerup だけで十分カバーされる
+            on:pointerdown=move |_| start_press()
+            on:pointerup=move |_| end_press()
+            on:pointercancel=move |_| cancel_press()
+            on:pointerleave=move |_| cancel_press()
+        >
+            <span class=format!("inline-flex items-center {}", icon_gap)>
+                {start_icon}
+                {children()}
+                {end_icon}
+            </span>
+        </button>
+    }
+}
+
+/// `Button`/`IconButton` 共通の、バリアントとカラーの組み合わせによる配色クラス
+pub(crate) fn variant_color_classes(variant: &ButtonVariant, color: &ButtonColor) -> String {
+    match (variant, color) {
         (ButtonVariant::Contained, ButtonColor::Primary) => {
             "bg-primary-500 text-white hover:bg-primary-600 focus:ring-primary-500".to_string()
         }
@@ -150,26 +281,15 @@ pub fn Button(
         (ButtonVariant::Text, ButtonColor::Inherit) => {
             "text-text-primary bg-transparent hover:bg-background-menu focus:ring-primary-500".to_string()
         }
-    };
-
-    base_classes.push(variant_color_classes);
-
-    // カスタムクラスの追加
-    if let Some(custom_class) = class {
-        base_classes.push(custom_class);
     }
+}
 
-    let class_string = base_classes.join(" ");
-
-    view! {
-        <button
-            type="button"
-            class=class_string
-            disabled=disabled
-            // クリックハンドラーは後で実装
-        >
-            {children()}
-        </button>
+/// `ButtonSize` ごとの、アイコンと本文の間隔（`IconAndText` の `icon_position` 相当）
+pub(crate) fn icon_gap_class(size: &ButtonSize) -> &'static str {
+    match size {
+        ButtonSize::Small => "gap-1.5",
+        ButtonSize::Medium => "gap-2",
+        ButtonSize::Large => "gap-2.5",
     }
 }
 
@@ -0,0 +1,84 @@
+// ButtonGroup / ButtonToolbar Component
+// bootstrap-rs の grouping をモデルにした、複数の `Button` を1つの視覚的な
+// コントロールへまとめるレイアウト層。`variant`/`color`/`size` は
+// `ButtonGroupContext` 経由で子の `Button` へ伝播し、個々のボタンで
+// 繰り返し指定する必要をなくす。内側の角丸・二重境界線の除去は
+// `ToggleButtonGroup` と同じ考え方で、Tailwindの子コンビネータに任せる
+
+use leptos::prelude::*;
+
+use super::button::{ButtonColor, ButtonGroupContext, ButtonSize, ButtonVariant};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ButtonGroupOrientation {
+    Horizontal,
+    Vertical,
+}
+
+#[component]
+pub fn ButtonGroup(
+    children: Children,
+    #[prop(optional)] variant: Option<ButtonVariant>,
+    #[prop(optional)] color: Option<ButtonColor>,
+    #[prop(optional)] size: Option<ButtonSize>,
+    #[prop(optional)] orientation: Option<ButtonGroupOrientation>,
+    #[prop(optional)] full_width: Option<bool>,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    let orientation = orientation.unwrap_or(ButtonGroupOrientation::Horizontal);
+    let full_width = full_width.unwrap_or(false);
+
+    provide_context(ButtonGroupContext { variant, color, size });
+
+    let orientation_classes = match orientation {
+        ButtonGroupOrientation::Horizontal => concat!(
+            "flex flex-row ",
+            "[&>*:not(:first-child)]:-ml-px ",
+            "[&>*:not(:first-child):not(:last-child)]:rounded-none ",
+            "[&>*:first-child:not(:last-child)]:rounded-r-none ",
+            "[&>*:last-child:not(:first-child)]:rounded-l-none",
+        ),
+        ButtonGroupOrientation::Vertical => concat!(
+            "flex flex-col ",
+            "[&>*:not(:first-child)]:-mt-px ",
+            "[&>*:not(:first-child):not(:last-child)]:rounded-none ",
+            "[&>*:first-child:not(:last-child)]:rounded-b-none ",
+            "[&>*:last-child:not(:first-child)]:rounded-t-none",
+        ),
+    };
+
+    view! {
+        <div class=format!(
+            "{} {} {}",
+            orientation_classes,
+            if full_width { "[&>*]:flex-1" } else { "" },
+            class.unwrap_or_default(),
+        )>
+            {children()}
+        </div>
+    }
+}
+
+#[component]
+pub fn ButtonToolbar(
+    children: Children,
+    /// 隣接する `ButtonGroup` 間の間隔。Tailwindの `gap-*` クラス名をそのまま渡す
+    #[prop(optional, into)]
+    gap: Option<String>,
+    #[prop(optional)] full_width: Option<bool>,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    let gap = gap.unwrap_or_else(|| "gap-2".to_string());
+    let full_width = full_width.unwrap_or(false);
+
+    view! {
+        <div class=format!(
+            "flex flex-row flex-wrap items-center {} {} {}",
+            gap,
+            if full_width { "[&>*]:flex-1" } else { "" },
+            class.unwrap_or_default(),
+        )>
+            {children()}
+        </div>
+    }
+}
@@ -0,0 +1,208 @@
+// Command Palette Component
+// メニューレジストリ（`list_commands`/`invoke_command`）をファジー検索で絞り込む
+// コマンドパレット。オーバーレイのバックドロップ/Show構成はDialogを踏襲する
+
+use std::collections::HashSet;
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::tauri_bridge::invoke;
+
+/// バックエンドの `list_commands` が返すコマンド1件分
+#[derive(Clone, Debug, Deserialize)]
+pub struct CommandInfo {
+    pub id: String,
+    pub label: String,
+    pub accelerator: Option<String>,
+}
+
+/// ファジーマッチの結果。スコアでソートし、`indices` でマッチ文字をハイライトする
+#[derive(Clone, Debug)]
+pub struct FuzzyMatch {
+    pub score: i32,
+    pub indices: Vec<usize>,
+}
+
+const BASE_MATCH_SCORE: i32 = 1;
+const CONSECUTIVE_BONUS: i32 = 15;
+const WORD_BOUNDARY_BONUS: i32 = 10;
+const GAP_PENALTY: i32 = 2;
+
+/// `query` の各文字が `candidate` の中に順番通りに（大文字小文字を無視して）現れるかを
+/// 判定するサブシーケンスマッチャー。連続マッチや単語境界（空白/`_`/`/`の直後、または
+/// 大文字への変化点）でスコアを加点し、マッチしない文字ではわずかに減点することで
+/// 先頭からの距離や飛び石マッチを自然にペナルティ化する。クエリを最後まで消費できな
+/// かった場合は `None` を返す
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            indices: Vec::new(),
+        });
+    }
+
+    let query_lower: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let candidate_lower: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut query_idx = 0usize;
+    let mut score: i32 = 0;
+    let mut indices = Vec::new();
+    let mut prev_matched = false;
+
+    for (i, &lower_c) in candidate_lower.iter().enumerate() {
+        if query_idx >= query_lower.len() {
+            break;
+        }
+
+        if lower_c == query_lower[query_idx] {
+            let is_word_boundary = i == 0
+                || matches!(candidate_chars[i - 1], ' ' | '_' | '/')
+                || (candidate_chars[i].is_uppercase() && !candidate_chars[i - 1].is_uppercase());
+
+            let mut bonus = BASE_MATCH_SCORE;
+            if prev_matched {
+                bonus += CONSECUTIVE_BONUS;
+            }
+            if is_word_boundary {
+                bonus += WORD_BOUNDARY_BONUS;
+            }
+
+            score += bonus;
+            indices.push(i);
+            prev_matched = true;
+            query_idx += 1;
+        } else {
+            score -= GAP_PENALTY;
+            prev_matched = false;
+        }
+    }
+
+    if query_idx < query_lower.len() {
+        return None;
+    }
+
+    Some(FuzzyMatch { score, indices })
+}
+
+#[derive(Serialize)]
+struct InvokeCommandArgs<'a> {
+    id: &'a str,
+}
+
+/// マッチした文字だけを太字にして `label` を描画する
+fn render_highlighted(label: &str, indices: &[usize]) -> impl IntoView {
+    let matched: HashSet<usize> = indices.iter().copied().collect();
+
+    label
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if matched.contains(&i) {
+                view! { <b class="text-primary-500">{c.to_string()}</b> }.into_any()
+            } else {
+                view! { <span>{c.to_string()}</span> }.into_any()
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
+#[component]
+pub fn CommandPalette(
+    #[prop(into)] open: Signal<bool>,
+    #[prop(optional, into)] on_close: Option<WriteSignal<bool>>,
+) -> impl IntoView {
+    let (query, set_query) = signal(String::new());
+    let (commands, set_commands) = signal(Vec::<CommandInfo>::new());
+
+    // 開くたびに最新のコマンド一覧を取得し直す
+    Effect::new(move |_| {
+        if open.get() {
+            spawn_local(async move {
+                let result = invoke("list_commands", JsValue::NULL).await;
+                if let Ok(parsed) = serde_wasm_bindgen::from_value::<Vec<CommandInfo>>(result) {
+                    set_commands.set(parsed);
+                }
+            });
+        }
+    });
+
+    let ranked = move || {
+        let q = query.get();
+        let mut matches: Vec<(CommandInfo, FuzzyMatch)> = commands
+            .get()
+            .into_iter()
+            .filter_map(|cmd| fuzzy_match(&q, &cmd.label).map(|m| (cmd, m)))
+            .collect();
+        matches.sort_by(|a, b| b.1.score.cmp(&a.1.score));
+        matches
+    };
+
+    let close = move || {
+        if let Some(set_open) = on_close {
+            set_open.set(false);
+        }
+    };
+
+    let run_command = move |id: String| {
+        close();
+        spawn_local(async move {
+            let args = serde_wasm_bindgen::to_value(&InvokeCommandArgs { id: &id }).unwrap();
+            let _ = invoke("invoke_command", args).await;
+        });
+    };
+
+    view! {
+        <Show when=move || open.get()>
+            <div
+                class="fixed inset-0 z-50 flex items-start justify-center bg-black/50 backdrop-blur-sm pt-24"
+                on:click=move |ev| {
+                    if ev.target() == ev.current_target() {
+                        close();
+                    }
+                }
+                on:keydown=move |ev| {
+                    if ev.key() == "Escape" {
+                        close();
+                    }
+                }
+            >
+                <div
+                    class="bg-background-paper rounded-lg shadow-lg w-full max-w-lg mx-4"
+                    on:click=|ev| ev.stop_propagation()
+                >
+                    <input
+                        class="w-full px-4 py-3 bg-transparent text-text-primary placeholder:text-text-secondary focus:outline-none border-b border-grey-600"
+                        placeholder="Type a command..."
+                        prop:value=query
+                        on:input=move |ev| set_query.set(event_target_value(&ev))
+                    />
+                    <ul class="max-h-80 overflow-y-auto py-2">
+                        {move || {
+                            ranked()
+                                .into_iter()
+                                .map(|(cmd, m)| {
+                                    let id = cmd.id.clone();
+                                    view! {
+                                        <li
+                                            class="px-4 py-2 text-text-primary hover:bg-background-menu cursor-pointer flex justify-between items-center gap-4"
+                                            on:click=move |_| run_command(id.clone())
+                                        >
+                                            <span>{render_highlighted(&cmd.label, &m.indices)}</span>
+                                            {cmd.accelerator.clone().map(|a| {
+                                                view! { <span class="text-xs text-text-secondary">{a}</span> }
+                                            })}
+                                        </li>
+                                    }
+                                })
+                                .collect::<Vec<_>>()
+                        }}
+                    </ul>
+                </div>
+            </div>
+        </Show>
+    }
+}
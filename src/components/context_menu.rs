@@ -0,0 +1,152 @@
+// Context Menu Component
+// `Dialog` は中央配置のバックドロップ型だが、右クリックメニューのようにポインタ座標へ
+// 直接配置するオーバーレイの primitive が無かったため追加する。表示直後に実サイズを
+// 測り、ビューポート端をはみ出す軸だけ反転させ、外側クリック/Escapeで閉じる
+
+use leptos::ev;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[component]
+pub fn ContextMenu(
+    children: Children,
+    #[prop(into)] open: Signal<bool>,
+    #[prop(into)] x: Signal<f64>,
+    #[prop(into)] y: Signal<f64>,
+    #[prop(optional, into)] on_close: Option<WriteSignal<bool>>,
+) -> impl IntoView {
+    let menu_ref = NodeRef::<leptos::html::Div>::new();
+    let (style, set_style) = signal(String::new());
+
+    let close = move || {
+        if let Some(set_open) = on_close {
+            set_open.set(false);
+        }
+    };
+
+    // 開く/アンカー座標が変わるたびに実サイズを測り直し、ビューポートをはみ出す
+    // 軸だけアンカー座標を反転させる
+    Effect::new(move |_| {
+        if !open.get() {
+            return;
+        }
+        let anchor_x = x.get();
+        let anchor_y = y.get();
+
+        let Some(el) = menu_ref.get() else {
+            set_style.set(format!("left: {}px; top: {}px", anchor_x, anchor_y));
+            return;
+        };
+
+        let rect = el.get_bounding_client_rect();
+        let window = web_sys::window().expect("no global `window` exists");
+        let viewport_w = window
+            .inner_width()
+            .ok()
+            .and_then(|v| v.as_f64())
+            .unwrap_or(f64::MAX);
+        let viewport_h = window
+            .inner_height()
+            .ok()
+            .and_then(|v| v.as_f64())
+            .unwrap_or(f64::MAX);
+
+        let left = if anchor_x + rect.width() > viewport_w {
+            (anchor_x - rect.width()).max(0.0)
+        } else {
+            anchor_x
+        };
+        let top = if anchor_y + rect.height() > viewport_h {
+            (anchor_y - rect.height()).max(0.0)
+        } else {
+            anchor_y
+        };
+
+        set_style.set(format!("left: {}px; top: {}px", left, top));
+    });
+
+    // 外側クリック/Escapeで閉じる。リスナーは開いている間だけ登録し、
+    // クローズ時に `on_cleanup` で必ず解除する
+    Effect::new(move |_| {
+        if !open.get() {
+            return;
+        }
+
+        let keydown_handle = window_event_listener(ev::keydown, move |ev| {
+            if ev.key() == "Escape" {
+                close();
+            }
+        });
+
+        let pointerdown_handle = window_event_listener(ev::pointerdown, move |ev| {
+            let inside = menu_ref
+                .get()
+                .zip(ev.target())
+                .and_then(|(el, target)| target.dyn_into::<web_sys::Node>().ok().map(|node| (el, node)))
+                .map(|(el, node)| el.contains(Some(&node)))
+                .unwrap_or(false);
+
+            if !inside {
+                close();
+            }
+        });
+
+        on_cleanup(move || {
+            keydown_handle.remove();
+            pointerdown_handle.remove();
+        });
+    });
+
+    let children_view = children();
+
+    view! {
+        <Show when=move || open.get()>
+            <div
+                node_ref=menu_ref
+                class="fixed z-50 min-w-[180px] bg-background-paper rounded-md shadow-lg border border-grey-600 py-1"
+                style=move || style.get()
+            >
+                {children_view}
+            </div>
+        </Show>
+    }
+}
+
+#[component]
+pub fn ContextMenuItem(
+    #[prop(into)] label: String,
+    #[prop(optional, into)] shortcut: Option<String>,
+    #[prop(optional)] disabled: Option<bool>,
+    #[prop(optional)] on_click: Option<Callback<()>>,
+) -> impl IntoView {
+    let disabled = disabled.unwrap_or(false);
+
+    view! {
+        <button
+            class=format!(
+                "w-full flex items-center justify-between gap-6 px-3 py-1.5 text-left text-sm {}",
+                if disabled {
+                    "text-text-secondary cursor-not-allowed opacity-50"
+                } else {
+                    "text-text-primary hover:bg-background-menu cursor-pointer"
+                }
+            )
+            disabled=disabled
+            on:click=move |_| {
+                if !disabled {
+                    if let Some(cb) = on_click {
+                        cb.run(());
+                    }
+                }
+            }
+        >
+            <span>{label}</span>
+            {shortcut.map(|s| view! { <span class="text-xs text-text-secondary">{s}</span> })}
+        </button>
+    }
+}
+
+#[component]
+pub fn ContextMenuSeparator() -> impl IntoView {
+    view! { <div class="border-t border-grey-600 my-1"></div> }
+}
@@ -0,0 +1,59 @@
+// IconButton Component
+// アイコン1つだけを操作対象にするボタン。Buttonの配色ロジックを共有しつつ、
+// 正方形のパディングと円形オプションを持つ。ラベルの無い操作になるため
+// `aria_label` を必須にしている
+
+use leptos::prelude::*;
+
+use super::button::{variant_color_classes, ButtonColor, ButtonSize, ButtonVariant};
+
+#[component]
+pub fn IconButton(
+    children: Children,
+    /// スクリーンリーダー向けのラベル。アイコンのみのボタンなので必須
+    #[prop(into)]
+    aria_label: String,
+    #[prop(optional)] variant: Option<ButtonVariant>,
+    #[prop(optional)] color: Option<ButtonColor>,
+    #[prop(optional)] size: Option<ButtonSize>,
+    #[prop(optional)] circular: Option<bool>,
+    #[prop(optional)] disabled: Option<bool>,
+    #[prop(optional)] on_click: Option<Callback<()>>,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    let variant = variant.unwrap_or(ButtonVariant::Text);
+    let color = color.unwrap_or(ButtonColor::Primary);
+    let size = size.unwrap_or(ButtonSize::Medium);
+    let circular = circular.unwrap_or(false);
+    let disabled = disabled.unwrap_or(false);
+
+    let padding = match size {
+        ButtonSize::Small => "p-1.5",
+        ButtonSize::Medium => "p-2",
+        ButtonSize::Large => "p-2.5",
+    };
+
+    let class_string = format!(
+        "inline-flex items-center justify-center transition-colors focus:outline-none focus:ring-2 focus:ring-offset-2 disabled:opacity-50 disabled:cursor-not-allowed {} {} {} {}",
+        if circular { "rounded-full" } else { "rounded-md" },
+        padding,
+        variant_color_classes(&variant, &color),
+        class.unwrap_or_default(),
+    );
+
+    view! {
+        <button
+            type="button"
+            class=class_string
+            disabled=disabled
+            aria-label=aria_label
+            on:click=move |_| {
+                if let Some(cb) = on_click {
+                    cb.run(());
+                }
+            }
+        >
+            {children()}
+        </button>
+    }
+}
@@ -0,0 +1,120 @@
+// Vertical Menu Component
+// Trezor T3T1 の縦型メニューに着想を得た、選択可能な項目を縦に積むナビゲーション
+// サーフェス。フォーム値を選ぶ `Select` とは異なり、設定画面やサイドバーのような
+// ナビゲーション/アクション用途を想定する。矢印キーでの上下移動とEnterでの実行は
+// `Select` と同じ、コンテキスト登録によるインデックス管理で行う
+
+use leptos::prelude::*;
+
+#[derive(Clone, Copy)]
+struct VerticalMenuContext {
+    highlighted: RwSignal<usize>,
+    items: StoredValue<Vec<(Callback<()>, bool)>>,
+}
+
+#[component]
+pub fn VerticalMenu(children: Children, #[prop(optional, into)] class: Option<String>) -> impl IntoView {
+    let highlighted = RwSignal::new(0usize);
+    let items = StoredValue::new(Vec::<(Callback<()>, bool)>::new());
+
+    provide_context(VerticalMenuContext { highlighted, items });
+
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| match ev.key().as_str() {
+        "ArrowDown" => {
+            ev.prevent_default();
+            items.with_value(|list| {
+                if !list.is_empty() {
+                    let len = list.len();
+                    highlighted.update(|h| *h = (*h + 1) % len);
+                }
+            });
+        }
+        "ArrowUp" => {
+            ev.prevent_default();
+            items.with_value(|list| {
+                if !list.is_empty() {
+                    let len = list.len();
+                    highlighted.update(|h| *h = (*h + len - 1) % len);
+                }
+            });
+        }
+        "Enter" => {
+            ev.prevent_default();
+            items.with_value(|list| {
+                if let Some((on_select, disabled)) = list.get(highlighted.get_untracked()) {
+                    if !disabled {
+                        on_select.run(());
+                    }
+                }
+            });
+        }
+        _ => {}
+    };
+
+    view! {
+        <div
+            class=format!(
+                "flex flex-col py-1 bg-background-paper rounded-md {}",
+                class.unwrap_or_default()
+            )
+            tabindex="0"
+            role="menu"
+            on:keydown=on_keydown
+        >
+            {children()}
+        </div>
+    }
+}
+
+#[component]
+pub fn VerticalMenuItem(
+    #[prop(into)] label: String,
+    #[prop(optional)] icon: Option<AnyView>,
+    #[prop(optional, into)] detail: Option<String>,
+    #[prop(optional)] chevron: Option<bool>,
+    #[prop(optional)] disabled: Option<bool>,
+    on_select: Callback<()>,
+) -> impl IntoView {
+    let disabled = disabled.unwrap_or(false);
+    let chevron = chevron.unwrap_or(false);
+    let ctx = use_context::<VerticalMenuContext>().expect("VerticalMenuItem must be used inside a VerticalMenu");
+
+    // 登録順がそのままハイライトのインデックスになる。`VerticalMenu`はchildren()を
+    // 描画順に評価するため、このpushの並びがDOM上の並びと一致する
+    let index = ctx.items.with_value(|list| list.len());
+    ctx.items.update_value(|list| list.push((on_select, disabled)));
+
+    let is_highlighted = move || ctx.highlighted.get() == index;
+
+    let select_this = move |_| {
+        if !disabled {
+            ctx.highlighted.set(index);
+            on_select.run(());
+        }
+    };
+
+    view! {
+        <button
+            type="button"
+            role="menuitem"
+            class=move || format!(
+                "w-full flex items-center gap-2 px-3 py-1.5 text-left text-sm text-text-primary {}",
+                if disabled {
+                    "opacity-50 cursor-not-allowed"
+                } else if is_highlighted() {
+                    "bg-background-menu"
+                } else {
+                    "hover:bg-background-menu"
+                }
+            )
+            disabled=disabled
+            on:click=select_this
+            on:mouseenter=move |_| ctx.highlighted.set(index)
+        >
+            {icon}
+            <span class="flex-1">{label}</span>
+            {detail.map(|d| view! { <span class="text-xs text-text-secondary">{d}</span> })}
+            {chevron.then(|| view! { <span class="text-text-secondary">"›"</span> })}
+        </button>
+    }
+}
@@ -0,0 +1,42 @@
+// UI Components Library (次世代コンポーネント群)
+// src-leptos-backup からの移行中に追加されたコンポーネントをここに集約する
+
+pub mod alert;
+pub mod breadcrumbs;
+pub mod button;
+pub mod button_group;
+pub mod checkbox;
+pub mod command_palette;
+pub mod context_menu;
+pub mod dialog;
+pub mod icon_button;
+pub mod menu;
+pub mod project_panel;
+pub mod select;
+pub mod switch;
+pub mod tab_bar;
+pub mod text_field;
+pub mod toast;
+pub mod toggle;
+pub mod tooltip;
+pub mod typography;
+
+pub use alert::{Alert, AlertSeverity};
+pub use breadcrumbs::{Breadcrumbs, SymbolSegment};
+pub use button::{Button, ButtonColor, ButtonSize, ButtonVariant};
+pub use button_group::{ButtonGroup, ButtonGroupOrientation, ButtonToolbar};
+pub use checkbox::Checkbox;
+pub use command_palette::{fuzzy_match, CommandInfo, CommandPalette, FuzzyMatch};
+pub use context_menu::{ContextMenu, ContextMenuItem, ContextMenuSeparator};
+pub use dialog::{invoke_on_confirm, ConfirmDialog, Dialog, DialogActions, DialogContent, DialogTitle};
+pub use icon_button::IconButton;
+pub use menu::{VerticalMenu, VerticalMenuItem};
+pub use project_panel::ProjectPanel;
+pub use select::{Select, SelectOption, SelectVariant};
+pub use switch::{Switch, SwitchColor};
+pub use tab_bar::{TabBar, TabDocument};
+pub use text_field::{TextField, TextFieldVariant};
+pub use toast::{use_toasts, Toast, ToastContainer, ToastContext, ToastProvider};
+pub use toggle::{ToggleButton, ToggleButtonGroup, ToggleOrientation};
+pub use tooltip::{Tooltip, TooltipPlacement};
+pub use typography::{Typography, TypographyColor, TypographyVariant};
@@ -0,0 +1,412 @@
+// Project Panel Component
+// 開いたフォルダをツリー表示するプロジェクトエクスプローラ。`read_dir`/`rename_path`/
+// `create_entry`/`delete_entry` をバックエンドに委譲し、選択されたファイルは
+// `on_open_file` を通じてエディタ側へ伝える。右クリックメニューは`ContextMenu`
+// プリミティブの上に構築する
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use serde::{Deserialize, Serialize};
+
+use crate::components::context_menu::{ContextMenu, ContextMenuItem, ContextMenuSeparator};
+use crate::tauri_bridge::invoke;
+
+/// バックエンドの `read_dir` が返すエントリ1件分
+#[derive(Clone, Debug, Deserialize)]
+struct DirEntry {
+    name: String,
+    path: String,
+    is_dir: bool,
+}
+
+#[derive(Serialize)]
+struct ReadDirArgs<'a> {
+    path: &'a str,
+}
+
+#[derive(Serialize)]
+struct RenamePathArgs<'a> {
+    from: &'a str,
+    to: &'a str,
+}
+
+#[derive(Serialize)]
+struct CreateEntryArgs<'a> {
+    parent: &'a str,
+    name: &'a str,
+    is_dir: bool,
+}
+
+#[derive(Serialize)]
+struct DeleteEntryArgs<'a> {
+    path: &'a str,
+}
+
+async fn read_dir(path: &str) -> Vec<DirEntry> {
+    let args = serde_wasm_bindgen::to_value(&ReadDirArgs { path }).unwrap();
+    let result = invoke("read_dir", args).await;
+    serde_wasm_bindgen::from_value(result).unwrap_or_default()
+}
+
+fn parent_of(path: &str) -> String {
+    path.rsplit_once('/').map(|(p, _)| p.to_string()).unwrap_or_default()
+}
+
+#[component]
+pub fn ProjectPanel(
+    /// ワークスペースのルートフォルダ（`open_folder` で選択されたパス）
+    #[prop(into)]
+    root: Signal<Option<String>>,
+    /// ファイルが選択されたときに、そのフルパスを伝える
+    #[prop(optional, into)]
+    on_open_file: Option<Callback<String>>,
+) -> impl IntoView {
+    // 右クリックメニューの表示状態。対象エントリと座標は別シグナルで保持し、
+    // Escape/外側クリックによる close は ContextMenu 自身に任せる（tab_bar.rsと同じ形）
+    let (menu_open, set_menu_open) = signal(false);
+    let (menu_x, set_menu_x) = signal(0.0f64);
+    let (menu_y, set_menu_y) = signal(0.0f64);
+    let (menu_entry, set_menu_entry) = signal(None::<DirEntry>);
+
+    let (dragging_path, set_dragging_path) = signal(None::<String>);
+    // New/Rename/Delete/移動の成功後、影響を受けたディレクトリの一覧をここへ積む。
+    // 該当パスを表示している `TreeNode` だけが読み直す
+    let (dirty_dirs, set_dirty_dirs) = signal(Vec::<String>::new());
+
+    view! {
+        <div class="h-full overflow-y-auto bg-background-paper text-text-primary text-sm select-none">
+            {move || {
+                root.get()
+                    .map(|root_path| {
+                        view! {
+                            <TreeNode
+                                path=root_path
+                                depth=0
+                                on_open_file=on_open_file
+                                set_menu_open=set_menu_open
+                                set_menu_x=set_menu_x
+                                set_menu_y=set_menu_y
+                                set_menu_entry=set_menu_entry
+                                dragging_path=dragging_path
+                                set_dragging_path=set_dragging_path
+                                dirty_dirs=dirty_dirs
+                                set_dirty_dirs=set_dirty_dirs
+                            />
+                        }
+                    })
+            }}
+            <EntryContextMenu
+                open=menu_open
+                set_menu_open=set_menu_open
+                x=menu_x
+                y=menu_y
+                entry=menu_entry
+                root=root
+                set_dirty_dirs=set_dirty_dirs
+            />
+        </div>
+    }
+}
+
+#[component]
+fn TreeNode(
+    path: String,
+    depth: usize,
+    on_open_file: Option<Callback<String>>,
+    set_menu_open: WriteSignal<bool>,
+    set_menu_x: WriteSignal<f64>,
+    set_menu_y: WriteSignal<f64>,
+    set_menu_entry: WriteSignal<Option<DirEntry>>,
+    dragging_path: ReadSignal<Option<String>>,
+    set_dragging_path: WriteSignal<Option<String>>,
+    dirty_dirs: ReadSignal<Vec<String>>,
+    set_dirty_dirs: WriteSignal<Vec<String>>,
+) -> impl IntoView {
+    let (entries, set_entries) = signal(Vec::<DirEntry>::new());
+    let (loaded, set_loaded) = signal(false);
+
+    let load = {
+        let path = path.clone();
+        move || {
+            let path = path.clone();
+            spawn_local(async move {
+                set_entries.set(read_dir(&path).await);
+                set_loaded.set(true);
+            });
+        }
+    };
+
+    load();
+
+    // New/Rename/Delete/移動が自分のディレクトリへ影響したときだけ読み直す
+    Effect::new({
+        let path = path.clone();
+        let load = load.clone();
+        move |_| {
+            if dirty_dirs.get().iter().any(|dir| dir == &path) {
+                load();
+            }
+        }
+    });
+
+    view! {
+        <ul class="list-none m-0 p-0">
+            <For
+                each=move || entries.get()
+                key=|entry| entry.path.clone()
+                let:entry
+            >
+                <EntryRow
+                    entry=entry
+                    depth=depth
+                    on_open_file=on_open_file
+                    set_menu_open=set_menu_open
+                    set_menu_x=set_menu_x
+                    set_menu_y=set_menu_y
+                    set_menu_entry=set_menu_entry
+                    dragging_path=dragging_path
+                    set_dragging_path=set_dragging_path
+                    dirty_dirs=dirty_dirs
+                    set_dirty_dirs=set_dirty_dirs
+                />
+            </For>
+            {move || (loaded.get() && entries.get().is_empty()).then(|| {
+                view! {
+                    <li
+                        class="text-text-secondary italic px-2"
+                        style=format!("padding-left: {}px", (depth + 1) * 16 + 8)
+                    >
+                        "(empty)"
+                    </li>
+                }
+            })}
+        </ul>
+    }
+}
+
+#[component]
+fn EntryRow(
+    entry: DirEntry,
+    depth: usize,
+    on_open_file: Option<Callback<String>>,
+    set_menu_open: WriteSignal<bool>,
+    set_menu_x: WriteSignal<f64>,
+    set_menu_y: WriteSignal<f64>,
+    set_menu_entry: WriteSignal<Option<DirEntry>>,
+    dragging_path: ReadSignal<Option<String>>,
+    set_dragging_path: WriteSignal<Option<String>>,
+    dirty_dirs: ReadSignal<Vec<String>>,
+    set_dirty_dirs: WriteSignal<Vec<String>>,
+) -> impl IntoView {
+    let (expanded, set_expanded) = signal(false);
+    let indent = format!("{}px", depth * 16 + 8);
+    let is_dir = entry.is_dir;
+    let entry_path = entry.path.clone();
+
+    let toggle_or_open = {
+        let entry_path = entry_path.clone();
+        move |ev: leptos::ev::MouseEvent| {
+            ev.stop_propagation();
+            if is_dir {
+                set_expanded.update(|e| *e = !*e);
+            } else if let Some(cb) = on_open_file {
+                cb.run(entry_path.clone());
+            }
+        }
+    };
+
+    let open_context_menu = {
+        let entry = entry.clone();
+        move |ev: leptos::ev::MouseEvent| {
+            ev.prevent_default();
+            ev.stop_propagation();
+            set_menu_x.set(ev.client_x() as f64);
+            set_menu_y.set(ev.client_y() as f64);
+            set_menu_entry.set(Some(entry.clone()));
+            set_menu_open.set(true);
+        }
+    };
+
+    let on_drag_start = {
+        let entry_path = entry_path.clone();
+        move |_| set_dragging_path.set(Some(entry_path.clone()))
+    };
+
+    let on_drop = {
+        let entry_path = entry_path.clone();
+        move |ev: leptos::ev::DragEvent| {
+            ev.prevent_default();
+            ev.stop_propagation();
+            if !is_dir {
+                return;
+            }
+            let Some(dragged) = dragging_path.get_untracked() else {
+                return;
+            };
+            let target_dir = entry_path.clone();
+            set_dragging_path.set(None);
+            spawn_local(async move {
+                let Some(name) = dragged.rsplit('/').next() else {
+                    return;
+                };
+                let to = format!("{}/{}", target_dir, name);
+                let args =
+                    serde_wasm_bindgen::to_value(&RenamePathArgs { from: &dragged, to: &to })
+                        .unwrap();
+                let source_dir = parent_of(&dragged);
+                let _ = invoke("rename_path", args).await;
+                set_dirty_dirs.set(vec![target_dir, source_dir]);
+            });
+        }
+    };
+
+    view! {
+        <li>
+            <div
+                class="flex items-center gap-1 px-2 py-0.5 hover:bg-background-menu cursor-pointer"
+                style=format!("padding-left: {}", indent)
+                draggable="true"
+                on:click=toggle_or_open
+                on:contextmenu=open_context_menu
+                on:dragstart=on_drag_start
+                on:dragover=move |ev: leptos::ev::DragEvent| ev.prevent_default()
+                on:drop=on_drop
+            >
+                <span class="w-3 text-text-secondary">
+                    {move || if is_dir { if expanded.get() { "▾" } else { "▸" } } else { "" }}
+                </span>
+                <span>{entry.name.clone()}</span>
+            </div>
+            {move || {
+                (is_dir && expanded.get())
+                    .then(|| {
+                        view! {
+                            <TreeNode
+                                path=entry_path.clone()
+                                depth=depth + 1
+                                on_open_file=on_open_file
+                                set_menu_open=set_menu_open
+                                set_menu_x=set_menu_x
+                                set_menu_y=set_menu_y
+                                set_menu_entry=set_menu_entry
+                                dragging_path=dragging_path
+                                set_dragging_path=set_dragging_path
+                                dirty_dirs=dirty_dirs
+                                set_dirty_dirs=set_dirty_dirs
+                            />
+                        }
+                    })
+            }}
+        </li>
+    }
+}
+
+#[component]
+fn EntryContextMenu(
+    #[prop(into)] open: Signal<bool>,
+    set_menu_open: WriteSignal<bool>,
+    #[prop(into)] x: Signal<f64>,
+    #[prop(into)] y: Signal<f64>,
+    entry: ReadSignal<Option<DirEntry>>,
+    #[prop(into)] root: Signal<Option<String>>,
+    set_dirty_dirs: WriteSignal<Vec<String>>,
+) -> impl IntoView {
+    let close = move || set_menu_open.set(false);
+
+    // `create_entry` が失敗した場合（名前の衝突やI/Oエラー）、バックエンドが
+    // `app-toast` イベントを発行して知らせる（commands/project.rsを参照）。ここでは
+    // 結果を個別にハンドリングせず、影響を受けたディレクトリの再読込だけ行う
+    let new_file = move |_| {
+        let Some(entry) = entry.get_untracked() else { return };
+        let parent_dir = if entry.is_dir { entry.path } else { parent_of(&entry.path) };
+        close();
+        spawn_local(async move {
+            let args = serde_wasm_bindgen::to_value(&CreateEntryArgs {
+                parent: &parent_dir,
+                name: "Untitled",
+                is_dir: false,
+            })
+            .unwrap();
+            let _ = invoke("create_entry", args).await;
+            set_dirty_dirs.set(vec![parent_dir]);
+        });
+    };
+
+    let new_folder = move |_| {
+        let Some(entry) = entry.get_untracked() else { return };
+        let parent_dir = if entry.is_dir { entry.path } else { parent_of(&entry.path) };
+        close();
+        spawn_local(async move {
+            let args = serde_wasm_bindgen::to_value(&CreateEntryArgs {
+                parent: &parent_dir,
+                name: "New Folder",
+                is_dir: true,
+            })
+            .unwrap();
+            let _ = invoke("create_entry", args).await;
+            set_dirty_dirs.set(vec![parent_dir]);
+        });
+    };
+
+    let rename = move |_| {
+        let Some(entry) = entry.get_untracked() else { return };
+        close();
+        let Some(new_name) = window().prompt_with_message("Rename to:").ok().flatten() else {
+            return;
+        };
+        if new_name.is_empty() {
+            return;
+        }
+        spawn_local(async move {
+            let parent = parent_of(&entry.path);
+            let to = format!("{}/{}", parent, new_name);
+            let args =
+                serde_wasm_bindgen::to_value(&RenamePathArgs { from: &entry.path, to: &to })
+                    .unwrap();
+            let _ = invoke("rename_path", args).await;
+            set_dirty_dirs.set(vec![parent]);
+        });
+    };
+
+    let delete = move |_| {
+        let Some(entry) = entry.get_untracked() else { return };
+        close();
+        spawn_local(async move {
+            let parent = parent_of(&entry.path);
+            let args = serde_wasm_bindgen::to_value(&DeleteEntryArgs { path: &entry.path })
+                .unwrap();
+            let _ = invoke("delete_entry", args).await;
+            set_dirty_dirs.set(vec![parent]);
+        });
+    };
+
+    let copy_relative_path = move |_| {
+        let Some(entry) = entry.get_untracked() else { return };
+        let workspace_root = root.get_untracked().unwrap_or_default();
+        let relative = entry
+            .path
+            .strip_prefix(&workspace_root)
+            .map(|rest| rest.trim_start_matches('/').to_string())
+            .unwrap_or(entry.path);
+        close();
+        if let Some(clipboard) = window().navigator().clipboard() {
+            let _ = clipboard.write_text(&relative);
+        }
+    };
+
+    view! {
+        <ContextMenu open=open x=x y=y on_close=set_menu_open>
+            <ContextMenuItem label="New File".to_string() on_click=Callback::new(new_file) />
+            <ContextMenuItem label="New Folder".to_string() on_click=Callback::new(new_folder) />
+            <ContextMenuSeparator />
+            <ContextMenuItem label="Rename".to_string() on_click=Callback::new(rename) />
+            <ContextMenuItem label="Delete".to_string() on_click=Callback::new(delete) />
+            <ContextMenuSeparator />
+            <ContextMenuItem label="Copy Relative Path".to_string() on_click=Callback::new(copy_relative_path) />
+        </ContextMenu>
+    }
+}
+
+fn window() -> web_sys::Window {
+    web_sys::window().expect("no global `window` exists")
+}
@@ -0,0 +1,347 @@
+// Select Component
+// bitque styled-select ライクな、ネイティブ <select> を使わないカスタムドロップダウン。
+// 値コンテナ＋シェブロン、位置固定のドロップダウンパネル、tip/helper_textの表示、
+// 外側クリック/Escapeでの close、クリック/Enter/Spaceでの open、矢印キーでの
+// ラップアラウンド・ナビゲーションを提供する。`value`/`set_value` のシグナルAPIは
+// 既存の呼び出し元 (App) がそのまま動くよう変更していない
+
+use leptos::ev;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[derive(Clone, PartialEq)]
+#[allow(dead_code)] // 将来使用予定のバリアント
+pub enum SelectVariant {
+    Outlined,
+    Filled,
+    Standard,
+}
+
+/// `Select`とその子`SelectOption`の間で、選択状態・ハイライト中インデックス・
+/// 登録済みオプション一覧を共有するコンテキスト
+#[derive(Clone, Copy)]
+struct SelectContext {
+    value: Signal<Option<String>>,
+    set_value: WriteSignal<Option<String>>,
+    set_open: WriteSignal<bool>,
+    highlighted: RwSignal<usize>,
+    /// (value, label, disabled) を描画順に登録したもの。矢印キーでのラップ
+    /// アラウンドと、閉じた値コンテナに表示する現在のラベルの検索に使う
+    options: StoredValue<Vec<(String, String, bool)>>,
+}
+
+#[component]
+pub fn Select(
+    value: ReadSignal<Option<String>>,
+    set_value: WriteSignal<Option<String>>,
+    children: Children,
+    #[prop(optional, into)] label: Option<String>,
+    #[prop(optional, into)] placeholder: Option<String>,
+    #[prop(optional)] variant: Option<SelectVariant>,
+    #[prop(optional)] disabled: Option<bool>,
+    #[prop(optional)] error: Option<bool>,
+    #[prop(optional, into)] helper_text: Option<String>,
+    /// フィールド下部に表示する補足テキスト（helper_textよりさらに軽い注記）
+    #[prop(optional, into)]
+    tip: Option<String>,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    let variant = variant.unwrap_or(SelectVariant::Outlined);
+    let disabled = disabled.unwrap_or(false);
+    let error = error.unwrap_or(false);
+    let placeholder = placeholder.unwrap_or_else(|| "Select...".to_string());
+
+    let (open, set_open) = signal(false);
+    let (anchor_style, set_anchor_style) = signal(String::new());
+    let highlighted = RwSignal::new(0usize);
+    let options = StoredValue::new(Vec::<(String, String, bool)>::new());
+    let trigger_ref = NodeRef::<leptos::html::Div>::new();
+    let panel_ref = NodeRef::<leptos::html::Div>::new();
+
+    provide_context(SelectContext {
+        value: value.into(),
+        set_value,
+        set_open,
+        highlighted,
+        options,
+    });
+
+    // `children()` はここで一度だけ評価する。`SelectOption` はマウント時に
+    // 自身をコンテキストへ登録するため、`<Show>` の内側に置いて開閉のたびに
+    // 評価し直すと、閉じている間はプリセットされた `value` のラベルが
+    // 引けず（未登録のまま）プレースホルダー表示のままになってしまう
+    let children_view = children();
+
+    let open_dropdown = move || {
+        if disabled {
+            return;
+        }
+        if let Some(el) = trigger_ref.get() {
+            let rect = el.get_bounding_client_rect();
+            set_anchor_style.set(format!(
+                "left: {}px; top: {}px; width: {}px",
+                rect.left(),
+                rect.bottom(),
+                rect.width()
+            ));
+        }
+        let current = value.get_untracked();
+        options.with_value(|opts| {
+            highlighted.set(
+                current
+                    .and_then(|v| opts.iter().position(|(ov, _, _)| ov == &v))
+                    .unwrap_or(0),
+            );
+        });
+        set_open.set(true);
+    };
+
+    // 開いている間だけ矢印キー/Enter/Escapeと外側クリックを購読する
+    Effect::new(move |_| {
+        if !open.get() {
+            return;
+        }
+
+        let keydown_handle = window_event_listener(ev::keydown, move |ev| {
+            match ev.key().as_str() {
+                "ArrowDown" => {
+                    ev.prevent_default();
+                    options.with_value(|opts| {
+                        if !opts.is_empty() {
+                            let len = opts.len();
+                            highlighted.update(|h| *h = (*h + 1) % len);
+                        }
+                    });
+                }
+                "ArrowUp" => {
+                    ev.prevent_default();
+                    options.with_value(|opts| {
+                        if !opts.is_empty() {
+                            let len = opts.len();
+                            highlighted.update(|h| *h = (*h + len - 1) % len);
+                        }
+                    });
+                }
+                "Enter" => {
+                    ev.prevent_default();
+                    options.with_value(|opts| {
+                        if let Some((v, _, opt_disabled)) = opts.get(highlighted.get_untracked()) {
+                            if !opt_disabled {
+                                set_value.set(Some(v.clone()));
+                                set_open.set(false);
+                            }
+                        }
+                    });
+                }
+                "Escape" => {
+                    set_open.set(false);
+                }
+                _ => {}
+            }
+        });
+
+        let pointerdown_handle = window_event_listener(ev::pointerdown, move |ev| {
+            let target_node = ev.target().and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+            let inside = target_node
+                .map(|node| {
+                    trigger_ref.get().is_some_and(|el| el.contains(Some(&node)))
+                        || panel_ref.get().is_some_and(|el| el.contains(Some(&node)))
+                })
+                .unwrap_or(false);
+
+            if !inside {
+                set_open.set(false);
+            }
+        });
+
+        on_cleanup(move || {
+            keydown_handle.remove();
+            pointerdown_handle.remove();
+        });
+    });
+
+    let current_label = move || {
+        let current = value.get();
+        options.with_value(|opts| {
+            current.and_then(|v| opts.iter().find(|(ov, _, _)| ov == &v).map(|(_, l, _)| l.clone()))
+        })
+    };
+
+    // ベースクラス
+    let mut base_classes = vec![
+        "w-full".to_string(),
+        "flex".to_string(),
+        "items-center".to_string(),
+        "justify-between".to_string(),
+        "gap-2".to_string(),
+        "px-3".to_string(),
+        "py-2".to_string(),
+        "rounded-md".to_string(),
+        "bg-background-default".to_string(),
+        "text-text-primary".to_string(),
+        "transition-colors".to_string(),
+        "focus:outline-none".to_string(),
+        "focus:ring-2".to_string(),
+        "disabled:opacity-50".to_string(),
+        "disabled:cursor-not-allowed".to_string(),
+        "cursor-pointer".to_string(),
+    ];
+
+    // バリアント別のクラス
+    match variant {
+        SelectVariant::Outlined => {
+            base_classes.push("border".to_string());
+            if error {
+                base_classes.push("border-error-500".to_string());
+                base_classes.push("focus:ring-error-500".to_string());
+            } else {
+                base_classes.push("border-grey-600".to_string());
+                base_classes.push("focus:ring-primary-500".to_string());
+            }
+        }
+        SelectVariant::Filled => {
+            base_classes.push("border-0".to_string());
+            base_classes.push("bg-background-paper".to_string());
+            if error {
+                base_classes.push("focus:ring-error-500".to_string());
+            } else {
+                base_classes.push("focus:ring-primary-500".to_string());
+            }
+        }
+        SelectVariant::Standard => {
+            base_classes.push("border-0".to_string());
+            base_classes.push("border-b-2".to_string());
+            if error {
+                base_classes.push("border-error-500".to_string());
+                base_classes.push("focus:ring-error-500".to_string());
+            } else {
+                base_classes.push("border-grey-600".to_string());
+                base_classes.push("focus:ring-primary-500".to_string());
+            }
+        }
+    }
+
+    if let Some(custom_class) = class.clone() {
+        base_classes.push(custom_class);
+    }
+
+    let trigger_class = base_classes.join(" ");
+
+    view! {
+        <div class="flex flex-col gap-1">
+            {label.map(|l| {
+                view! {
+                    <label class="text-sm font-medium text-text-primary">
+                        {l}
+                    </label>
+                }
+            })}
+            <div
+                node_ref=trigger_ref
+                class=trigger_class
+                tabindex=if disabled { "-1" } else { "0" }
+                role="button"
+                on:click=move |_| open_dropdown()
+                on:keydown=move |ev| {
+                    match ev.key().as_str() {
+                        "Enter" | " " => {
+                            ev.prevent_default();
+                            open_dropdown();
+                        }
+                        _ => {}
+                    }
+                }
+            >
+                <span class=move || if current_label().is_some() {
+                    "text-text-primary"
+                } else {
+                    "text-text-secondary"
+                }>
+                    {move || current_label().unwrap_or_else(|| placeholder.clone())}
+                </span>
+                <span class="text-text-secondary">"⌄"</span>
+            </div>
+
+            <Show when=move || open.get()>
+                <div
+                    node_ref=panel_ref
+                    class="fixed z-50 max-h-64 overflow-y-auto bg-background-paper rounded-md shadow-lg border border-grey-600 py-1"
+                    style=move || anchor_style.get()
+                >
+                    {children_view}
+                </div>
+            </Show>
+
+            {tip.map(|text| {
+                view! { <span class="text-xs text-text-secondary">{text}</span> }
+            })}
+            {helper_text.map(|text| {
+                let helper_class = if error {
+                    "text-sm text-error-500"
+                } else {
+                    "text-sm text-text-secondary"
+                };
+                view! {
+                    <span class=helper_class>
+                        {text}
+                    </span>
+                }
+            })}
+        </div>
+    }
+}
+
+#[component]
+pub fn SelectOption(
+    value: String,
+    #[prop(into)] label: String,
+    children: Children,
+    #[prop(optional)] disabled: Option<bool>,
+) -> impl IntoView {
+    let disabled = disabled.unwrap_or(false);
+    let ctx = use_context::<SelectContext>().expect("SelectOption must be used inside a Select");
+
+    // 自身をグループへ登録する。Select は children() を描画順に評価するため、
+    // ここでの push 順序がそのままハイライトのインデックスになる
+    let index = ctx.options.with_value(|opts| opts.len());
+    ctx.options.update_value(|opts| opts.push((value.clone(), label, disabled)));
+
+    let is_current = {
+        let value = value.clone();
+        move || ctx.value.get().as_deref() == Some(value.as_str())
+    };
+    let is_highlighted = move || ctx.highlighted.get() == index;
+
+    let select_this = {
+        let value = value.clone();
+        move |_| {
+            if !disabled {
+                ctx.set_value.set(Some(value.clone()));
+                ctx.set_open.set(false);
+            }
+        }
+    };
+
+    view! {
+        <button
+            type="button"
+            class=move || format!(
+                "w-full flex items-center justify-between gap-6 px-3 py-1.5 text-left text-sm {} {}",
+                if disabled {
+                    "text-text-secondary cursor-not-allowed opacity-50"
+                } else {
+                    "text-text-primary cursor-pointer"
+                },
+                if is_highlighted() || is_current() {
+                    "bg-background-menu"
+                } else {
+                    "hover:bg-background-menu"
+                }
+            )
+            disabled=disabled
+            on:click=select_this
+        >
+            {children()}
+        </button>
+    }
+}
@@ -0,0 +1,232 @@
+// Editor Tab Bar Component
+// これまでメニューの `open_file` はフロントエンドへ通知するだけで、複数ファイルを
+// 開いた状態を管理する術がなかった。開いているドキュメントをリアクティブなタブ列として
+// 保持し、アクティブ表示・ダーティドット・クローズボタン・横スクロール・右クリックの
+// コンテキストメニューを提供する。`menu-event` を購読することで、バックエンドの
+// メニュー操作が既存のハンドラと同じ経路でフロントエンドへ往復する形を保つ
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::prelude::*;
+
+use crate::components::context_menu::{ContextMenu, ContextMenuItem, ContextMenuSeparator};
+use crate::tauri_bridge::listen;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "dialog"])]
+    async fn open(options: JsValue) -> JsValue;
+}
+
+/// タブ1件分のドキュメント情報
+#[derive(Clone, Debug, PartialEq)]
+pub struct TabDocument {
+    /// 一意キーとして使うフルパス
+    pub id: String,
+    pub title: String,
+    pub dirty: bool,
+}
+
+fn title_from_path(path: &str) -> String {
+    path.rsplit('/').next().unwrap_or(path).to_string()
+}
+
+fn open_tab(path: String, set_tabs: WriteSignal<Vec<TabDocument>>, set_active_id: WriteSignal<Option<String>>) {
+    let title = title_from_path(&path);
+    set_tabs.update(|list| {
+        if !list.iter().any(|t| t.id == path) {
+            list.push(TabDocument { id: path.clone(), title, dirty: false });
+        }
+    });
+    set_active_id.set(Some(path));
+}
+
+fn close_tab(
+    id: &str,
+    tabs: ReadSignal<Vec<TabDocument>>,
+    set_tabs: WriteSignal<Vec<TabDocument>>,
+    active_id: ReadSignal<Option<String>>,
+    set_active_id: WriteSignal<Option<String>>,
+) {
+    set_tabs.update(|list| list.retain(|t| t.id != id));
+    if active_id.get_untracked().as_deref() == Some(id) {
+        let next = tabs.get_untracked().last().map(|t| t.id.clone());
+        set_active_id.set(next);
+    }
+}
+
+async fn pick_file() -> Option<String> {
+    let options = js_sys::Object::new();
+    js_sys::Reflect::set(&options, &JsValue::from_str("multiple"), &JsValue::from_bool(false)).ok()?;
+    open(options.into()).await.as_string()
+}
+
+#[component]
+pub fn TabBar(
+    /// アクティブなペインの最大化をトグルする（実際のレイアウト処理は呼び出し元が持つ）
+    #[prop(optional, into)]
+    on_toggle_zoom: Option<Callback<String>>,
+) -> impl IntoView {
+    let (tabs, set_tabs) = signal(Vec::<TabDocument>::new());
+    let (active_id, set_active_id) = signal(None::<String>);
+
+    // 右クリックメニューの表示状態。対象タブIDと座標は別シグナルで保持し、
+    // Escape/外側クリックによる close は ContextMenu 自身に任せる
+    let (menu_open, set_menu_open) = signal(false);
+    let (menu_x, set_menu_x) = signal(0.0f64);
+    let (menu_y, set_menu_y) = signal(0.0f64);
+    let (menu_tab_id, set_menu_tab_id) = signal(String::new());
+
+    // バックエンドの `menu-event` を購読し、open_file/close をタブ操作へ反映する
+    Effect::new(move |_| {
+        spawn_local(async move {
+            let closure = Closure::wrap(Box::new(move |event: JsValue| {
+                let Ok(payload) = js_sys::Reflect::get(&event, &JsValue::from_str("payload")) else {
+                    return;
+                };
+                let Some(signal_name) = payload.as_string() else {
+                    return;
+                };
+                match signal_name.as_str() {
+                    "open_file" => {
+                        spawn_local(async move {
+                            if let Some(path) = pick_file().await {
+                                open_tab(path, set_tabs, set_active_id);
+                            }
+                        });
+                    }
+                    "close" => {
+                        if let Some(id) = active_id.get_untracked() {
+                            close_tab(&id, tabs, set_tabs, active_id, set_active_id);
+                        }
+                    }
+                    _ => {}
+                }
+            }) as Box<dyn FnMut(JsValue)>);
+
+            let _ = listen("menu-event", closure.as_ref().unchecked_ref()).await;
+            // アプリの生存期間中、このタブバーは購読し続ける
+            closure.forget();
+        });
+    });
+
+    let close_active_menu = move || {
+        let tab_id = menu_tab_id.get_untracked();
+        close_tab(&tab_id, tabs, set_tabs, active_id, set_active_id);
+        set_menu_open.set(false);
+    };
+
+    let close_others = move |_| {
+        let tab_id = menu_tab_id.get_untracked();
+        set_tabs.update(|list| list.retain(|t| t.id == tab_id));
+        set_active_id.set(Some(tab_id));
+        set_menu_open.set(false);
+    };
+
+    let close_all = move |_| {
+        set_tabs.update(|list| list.clear());
+        set_active_id.set(None);
+        set_menu_open.set(false);
+    };
+
+    let close_to_right = move |_| {
+        let tab_id = menu_tab_id.get_untracked();
+        set_tabs.update(|list| {
+            if let Some(pos) = list.iter().position(|t| t.id == tab_id) {
+                list.truncate(pos + 1);
+            }
+        });
+        if let Some(active) = active_id.get_untracked() {
+            if !tabs.get_untracked().iter().any(|t| t.id == active) {
+                let next = tabs.get_untracked().last().map(|t| t.id.clone());
+                set_active_id.set(next);
+            }
+        }
+        set_menu_open.set(false);
+    };
+
+    let copy_path = move |_| {
+        let tab_id = menu_tab_id.get_untracked();
+        if let Some(clipboard) = web_sys::window().and_then(|w| w.navigator().clipboard()) {
+            let _ = clipboard.write_text(&tab_id);
+        }
+        set_menu_open.set(false);
+    };
+
+    let toggle_zoom = move |_| {
+        let tab_id = menu_tab_id.get_untracked();
+        if let Some(cb) = on_toggle_zoom {
+            cb.run(tab_id);
+        }
+        set_menu_open.set(false);
+    };
+
+    view! {
+        <div class="flex items-center bg-background-paper border-b border-grey-600 overflow-x-auto whitespace-nowrap">
+            <For
+                each=move || tabs.get()
+                key=|tab| tab.id.clone()
+                let:tab
+            >
+                {
+                    let id = tab.id.clone();
+                    let is_active = move || active_id.get().as_deref() == Some(id.as_str());
+                    let activate = {
+                        let id = tab.id.clone();
+                        move |_| set_active_id.set(Some(id.clone()))
+                    };
+                    let close_this = {
+                        let id = tab.id.clone();
+                        move |ev: leptos::ev::MouseEvent| {
+                            ev.stop_propagation();
+                            close_tab(&id, tabs, set_tabs, active_id, set_active_id);
+                        }
+                    };
+                    let open_menu = {
+                        let id = tab.id.clone();
+                        move |ev: leptos::ev::MouseEvent| {
+                            ev.prevent_default();
+                            ev.stop_propagation();
+                            set_menu_tab_id.set(id.clone());
+                            set_menu_x.set(ev.client_x() as f64);
+                            set_menu_y.set(ev.client_y() as f64);
+                            set_menu_open.set(true);
+                        }
+                    };
+
+                    view! {
+                        <div
+                            class=move || format!(
+                                "flex items-center gap-2 px-3 py-2 border-r border-grey-600 cursor-pointer shrink-0 {}",
+                                if is_active() { "bg-background-default text-text-primary" } else { "text-text-secondary hover:bg-background-menu" }
+                            )
+                            on:click=activate
+                            on:contextmenu=open_menu
+                        >
+                            {tab.dirty.then(|| view! { <span class="w-1.5 h-1.5 rounded-full bg-text-secondary"></span> })}
+                            <span class="text-sm">{tab.title.clone()}</span>
+                            <button
+                                class="text-text-secondary hover:text-text-primary"
+                                on:click=close_this
+                            >
+                                "×"
+                            </button>
+                        </div>
+                    }
+                }
+            </For>
+        </div>
+
+        <ContextMenu open=menu_open x=menu_x y=menu_y on_close=set_menu_open>
+            <ContextMenuItem label="Close".to_string() on_click=Callback::new(move |_| close_active_menu()) />
+            <ContextMenuItem label="Close Others".to_string() on_click=Callback::new(close_others) />
+            <ContextMenuItem label="Close All".to_string() on_click=Callback::new(close_all) />
+            <ContextMenuItem label="Close to the Right".to_string() on_click=Callback::new(close_to_right) />
+            <ContextMenuSeparator />
+            <ContextMenuItem label="Copy Path".to_string() on_click=Callback::new(copy_path) />
+            <ContextMenuSeparator />
+            <ContextMenuItem label="Toggle Zoom".to_string() on_click=Callback::new(toggle_zoom) />
+        </ContextMenu>
+    }
+}
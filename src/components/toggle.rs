@@ -0,0 +1,167 @@
+// Toggle Button Component
+// MUI ToggleButton/ToggleButtonGroup相当のLeptos実装。`ButtonGroup`が無くても先に
+// 実装できるよう、隣接ボタンの角丸つぶしはTailwindの子コンビネータ（`[&>*]`）で
+// CSSだけで解決し、選択状態の伝搬はコンテキスト経由でグループから子へ渡す
+
+use leptos::prelude::*;
+
+use super::button::ButtonSize;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ToggleOrientation {
+    Horizontal,
+    Vertical,
+}
+
+/// 排他選択（`Option<T>`）または複数選択（`Vec<T>`）のどちらかを保持する
+#[derive(Clone)]
+enum SelectionMode<T: Clone + PartialEq + 'static> {
+    Single {
+        value: ReadSignal<Option<T>>,
+        set_value: WriteSignal<Option<T>>,
+    },
+    Multiple {
+        value: ReadSignal<Vec<T>>,
+        set_value: WriteSignal<Vec<T>>,
+    },
+}
+
+impl<T: Clone + PartialEq + 'static> SelectionMode<T> {
+    fn is_selected(&self, candidate: &T) -> bool {
+        match self {
+            SelectionMode::Single { value, .. } => value.get().as_ref() == Some(candidate),
+            SelectionMode::Multiple { value, .. } => value.get().iter().any(|v| v == candidate),
+        }
+    }
+
+    fn toggle(&self, candidate: T) {
+        match self {
+            SelectionMode::Single { value, set_value } => {
+                if value.get_untracked().as_ref() == Some(&candidate) {
+                    set_value.set(None);
+                } else {
+                    set_value.set(Some(candidate));
+                }
+            }
+            SelectionMode::Multiple { value, set_value } => {
+                let mut list = value.get_untracked();
+                if let Some(pos) = list.iter().position(|v| v == &candidate) {
+                    list.remove(pos);
+                } else {
+                    list.push(candidate);
+                }
+                set_value.set(list);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ToggleGroupContext<T: Clone + PartialEq + 'static>(StoredValue<SelectionMode<T>>);
+
+/// 排他選択/複数選択どちらのモードで動くかを、渡されたシグナルの組み合わせから選ぶ。
+/// `ToggleButtonGroup` は単一コンポーネントのまま両モードを受け付ける
+#[component]
+pub fn ToggleButtonGroup<T>(
+    children: Children,
+    #[prop(optional)] value: Option<ReadSignal<Option<T>>>,
+    #[prop(optional)] set_value: Option<WriteSignal<Option<T>>>,
+    #[prop(optional)] multi_value: Option<ReadSignal<Vec<T>>>,
+    #[prop(optional)] set_multi_value: Option<WriteSignal<Vec<T>>>,
+    #[prop(optional)] orientation: Option<ToggleOrientation>,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let orientation = orientation.unwrap_or(ToggleOrientation::Horizontal);
+
+    let mode = match (value, set_value, multi_value, set_multi_value) {
+        (Some(value), Some(set_value), _, _) => SelectionMode::Single { value, set_value },
+        (_, _, Some(value), Some(set_value)) => SelectionMode::Multiple { value, set_value },
+        _ => panic!(
+            "ToggleButtonGroup requires either (value, set_value) for exclusive selection or (multi_value, set_multi_value) for multiple selection"
+        ),
+    };
+
+    provide_context(ToggleGroupContext(StoredValue::new(mode)));
+
+    // 隣接する子の角丸をButtonGroupと同じ考え方でつぶす。子の数を事前に知らなくて
+    // 済むよう、Rust側でインデックスを追跡せずTailwindの子コンビネータに任せる
+    let orientation_classes = match orientation {
+        ToggleOrientation::Horizontal => concat!(
+            "flex flex-row ",
+            "[&>*:not(:first-child)]:-ml-px ",
+            "[&>*:not(:first-child):not(:last-child)]:rounded-none ",
+            "[&>*:first-child:not(:last-child)]:rounded-r-none ",
+            "[&>*:last-child:not(:first-child)]:rounded-l-none",
+        ),
+        ToggleOrientation::Vertical => concat!(
+            "flex flex-col ",
+            "[&>*:not(:first-child)]:-mt-px ",
+            "[&>*:not(:first-child):not(:last-child)]:rounded-none ",
+            "[&>*:first-child:not(:last-child)]:rounded-b-none ",
+            "[&>*:last-child:not(:first-child)]:rounded-t-none",
+        ),
+    };
+
+    view! {
+        <div class=format!("{} {}", orientation_classes, class.unwrap_or_default())>
+            {children()}
+        </div>
+    }
+}
+
+#[component]
+pub fn ToggleButton<T>(
+    children: Children,
+    value: T,
+    #[prop(optional)] size: Option<ButtonSize>,
+    #[prop(optional)] disabled: Option<bool>,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + 'static,
+{
+    let ctx = use_context::<ToggleGroupContext<T>>()
+        .expect("ToggleButton must be rendered inside a ToggleButtonGroup");
+    let size = size.unwrap_or(ButtonSize::Medium);
+    let disabled = disabled.unwrap_or(false);
+
+    let size_classes = match size {
+        ButtonSize::Small => "px-3 py-1.5 text-sm",
+        ButtonSize::Medium => "px-4 py-2 text-base",
+        ButtonSize::Large => "px-6 py-3 text-lg",
+    };
+
+    let selected = {
+        let value = value.clone();
+        move || ctx.0.with_value(|mode| mode.is_selected(&value))
+    };
+
+    let toggle = move |_| {
+        if !disabled {
+            ctx.0.with_value(|mode| mode.toggle(value.clone()));
+        }
+    };
+
+    view! {
+        <button
+            type="button"
+            class=move || format!(
+                "inline-flex items-center justify-center font-medium rounded-md border border-grey-600 transition-colors focus:outline-none focus:ring-2 focus:ring-primary-500 disabled:opacity-50 disabled:cursor-not-allowed {} {} {}",
+                size_classes,
+                if selected() {
+                    "bg-primary-500 text-white ring-2 ring-inset ring-primary-600"
+                } else {
+                    "bg-transparent text-text-primary hover:bg-background-menu"
+                },
+                class.clone().unwrap_or_default()
+            )
+            disabled=disabled
+            on:click=toggle
+        >
+            {children()}
+        </button>
+    }
+}
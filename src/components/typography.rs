@@ -0,0 +1,113 @@
+// Typography Component
+// MUI TypographyのLeptos実装
+
+use leptos::prelude::*;
+
+#[derive(Clone, PartialEq)]
+#[allow(dead_code)] // 将来使用予定のバリアント
+pub enum TypographyVariant {
+    H1,
+    H2,
+    H3,
+    H4,
+    H5,
+    H6,
+    Subtitle1,
+    Subtitle2,
+    Body1,
+    Body2,
+    Caption,
+    Overline,
+}
+
+#[derive(Clone, PartialEq)]
+#[allow(dead_code)] // 将来使用予定のバリアント
+pub enum TypographyColor {
+    Primary,
+    Secondary,
+    Error,
+    Warning,
+    Success,
+    Info,
+    Inherit,
+}
+
+/// バリアントごとのデフォルトのHTML要素名
+fn default_component(variant: &TypographyVariant) -> &'static str {
+    match variant {
+        TypographyVariant::H1 => "h1",
+        TypographyVariant::H2 => "h2",
+        TypographyVariant::H3 => "h3",
+        TypographyVariant::H4 => "h4",
+        TypographyVariant::H5 => "h5",
+        TypographyVariant::H6 => "h6",
+        TypographyVariant::Caption | TypographyVariant::Overline => "span",
+        TypographyVariant::Subtitle1
+        | TypographyVariant::Subtitle2
+        | TypographyVariant::Body1
+        | TypographyVariant::Body2 => "p",
+    }
+}
+
+#[component]
+pub fn Typography(
+    children: Children,
+    #[prop(optional)] variant: Option<TypographyVariant>,
+    #[prop(optional)] color: Option<TypographyColor>,
+    // レンダリングするHTML要素を上書きする（例: H2相当の見た目をspanで出したい場合）。
+    // h1〜h6/span/div 以外の値は p として描画される
+    #[prop(optional, into)] component: Option<String>,
+    #[prop(optional, into)] class: Option<String>,
+) -> impl IntoView {
+    let variant = variant.unwrap_or(TypographyVariant::Body1);
+    let color = color.unwrap_or(TypographyColor::Inherit);
+    let tag = component
+        .unwrap_or_else(|| default_component(&variant).to_string());
+
+    // バリアント別のクラス
+    let variant_class = match variant {
+        TypographyVariant::H1 => "text-4xl font-bold",
+        TypographyVariant::H2 => "text-3xl font-bold",
+        TypographyVariant::H3 => "text-2xl font-semibold",
+        TypographyVariant::H4 => "text-xl font-semibold",
+        TypographyVariant::H5 => "text-lg font-medium",
+        TypographyVariant::H6 => "text-base font-medium",
+        TypographyVariant::Subtitle1 => "text-base font-medium",
+        TypographyVariant::Subtitle2 => "text-sm font-medium",
+        TypographyVariant::Body1 => "text-base",
+        TypographyVariant::Body2 => "text-sm",
+        TypographyVariant::Caption => "text-xs",
+        TypographyVariant::Overline => "text-xs uppercase tracking-wider",
+    };
+
+    // カラー別のクラス
+    let color_class = match color {
+        TypographyColor::Primary => "text-primary-500",
+        TypographyColor::Secondary => "text-text-secondary",
+        TypographyColor::Error => "text-error-500",
+        TypographyColor::Warning => "text-warning-500",
+        TypographyColor::Success => "text-success-500",
+        TypographyColor::Info => "text-info-500",
+        TypographyColor::Inherit => "text-text-primary",
+    };
+
+    let class_string = format!(
+        "{} {} {}",
+        variant_class,
+        color_class,
+        class.unwrap_or_default()
+    );
+
+    // variant / component propから決まったタグ名ごとに分岐して実際の要素を出し分ける
+    match tag.as_str() {
+        "h1" => view! { <h1 class=class_string>{children()}</h1> }.into_any(),
+        "h2" => view! { <h2 class=class_string>{children()}</h2> }.into_any(),
+        "h3" => view! { <h3 class=class_string>{children()}</h3> }.into_any(),
+        "h4" => view! { <h4 class=class_string>{children()}</h4> }.into_any(),
+        "h5" => view! { <h5 class=class_string>{children()}</h5> }.into_any(),
+        "h6" => view! { <h6 class=class_string>{children()}</h6> }.into_any(),
+        "span" => view! { <span class=class_string>{children()}</span> }.into_any(),
+        "div" => view! { <div class=class_string>{children()}</div> }.into_any(),
+        _ => view! { <p class=class_string>{children()}</p> }.into_any(),
+    }
+}
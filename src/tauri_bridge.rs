@@ -0,0 +1,14 @@
+// Tauri IPCブリッジ
+// `src-leptos-backup/app.rs` で直書きされていた `invoke` extern 宣言を、
+// `src/components` 配下の複数コンポーネントから共有できるよう切り出したもの
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"])]
+    pub async fn invoke(cmd: &str, args: JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "event"])]
+    pub async fn listen(event: &str, handler: &js_sys::Function) -> JsValue;
+}